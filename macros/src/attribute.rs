@@ -0,0 +1,133 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse2, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned, visit_mut::VisitMut,
+    Expr, GenericArgument, Ident, ItemFn, Macro, PathArguments, ReturnType, Token, Type,
+};
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "#[error_log] takes no arguments",
+        ));
+    }
+    let mut fun: ItemFn = parse2(item)?;
+    let ReturnType::Type(_, ret) = &fun.sig.output else {
+        return Err(syn::Error::new(
+            fun.sig.span(),
+            "#[error_log] requires a function returning an ErrorLog",
+        ));
+    };
+    let log = Ident::new("log", Span::call_site());
+    let ok_type = ok_type(ret).map(|ty| quote!(: #ty));
+    let init = match preset_constructor(ret) {
+        Some(constructor) => quote!(<#ret>::#constructor()),
+        None => quote!(::core::default::Default::default()),
+    };
+    let mut body = fun.block;
+    RewriteTry {
+        log: &log,
+        changed: false,
+    }
+    .visit_block_mut(&mut body);
+    fun.block = parse_quote_spanned! {body.span()=> {
+        #[allow(unused_mut)]
+        let mut #log: #ret = #init;
+        let ok #ok_type = #body;
+        #log.set_ok(ok);
+        #log
+    }};
+    Ok(quote!(#fun))
+}
+
+/// Constructor loading the optimized settings of presets like `ErrorLogAnyhow`, detected by the name of the return type
+fn preset_constructor(ret: &Type) -> Option<Ident> {
+    let Type::Path(path) = ret else {
+        return None;
+    };
+    let name = match path.path.segments.last()?.ident.to_string().as_str() {
+        "ErrorLogAnyhow" => "new_anyhow",
+        "ErrorLogEyre" => "new_eyre",
+        "ErrorLogMiette" => "new_miette",
+        _ => return None,
+    };
+    Some(Ident::new(name, Span::call_site()))
+}
+
+/// First generic argument of the return type, which is `T` for `ErrorLog<T, E>` and all presets
+fn ok_type(ret: &Type) -> Option<&Type> {
+    let Type::Path(path) = ret else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(args) = &path.path.segments.last()?.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+/**
+Replaces `?` and `return` in the function body, skipping closures, async blocks and items.
+
+Arguments of macro invocations are rewritten if they are comma separated expressions, like for `println!`.
+*/
+struct RewriteTry<'a> {
+    log: &'a Ident,
+    /// Whether anything got replaced
+    changed: bool,
+}
+
+impl VisitMut for RewriteTry<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let log = self.log;
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) => {}
+            Expr::Try(inner) => {
+                self.visit_expr_mut(&mut inner.expr);
+                let res = &inner.expr;
+                self.changed = true;
+                *expr = Expr::Verbatim(quote_spanned! {inner.question_token.span=>
+                    match #log.push_result(#res) {
+                        ::core::option::Option::Some(ok) => ok,
+                        ::core::option::Option::None => return #log,
+                    }
+                });
+            }
+            Expr::Return(ret) => {
+                if let Some(value) = &mut ret.expr {
+                    self.visit_expr_mut(value);
+                }
+                let set_ok = ret.expr.as_ref().map(|value| quote!(#log.set_ok(#value);));
+                self.changed = true;
+                *expr = Expr::Verbatim(quote_spanned! {ret.span()=>
+                    {
+                        #set_ok
+                        return #log
+                    }
+                });
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+    fn visit_item_mut(&mut self, _: &mut syn::Item) {}
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        let Ok(mut args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+        let mut rewrite = RewriteTry {
+            log: self.log,
+            changed: false,
+        };
+        for arg in &mut args {
+            rewrite.visit_expr_mut(arg);
+        }
+        if rewrite.changed {
+            mac.tokens = quote!(#args);
+            self.changed = true;
+        }
+    }
+}
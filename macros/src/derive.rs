@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse2, Attribute, Data, DeriveInput, LitStr};
+
+/// Values of `#[entry(...)]` attributes
+#[derive(Default, Clone)]
+struct EntryAttr {
+    code: Option<LitStr>,
+    help: Option<LitStr>,
+    level: Option<TokenStream>,
+}
+
+impl EntryAttr {
+    /// Parse attributes, using `self` as defaults
+    fn parse(mut self, attrs: &[Attribute]) -> syn::Result<Self> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("entry")) {
+            attr.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("code") {
+                    self.code = Some(value);
+                } else if meta.path.is_ident("help") {
+                    self.help = Some(value);
+                } else if meta.path.is_ident("level") {
+                    let level = match value.value().to_lowercase().as_str() {
+                        "off" => quote!(Off),
+                        "error" => quote!(Error),
+                        "warn" | "warning" => quote!(Warn),
+                        "info" => quote!(Info),
+                        "debug" => quote!(Debug),
+                        "trace" => quote!(Trace),
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected one of: error, warn, info, debug, trace, off",
+                            ))
+                        }
+                    };
+                    self.level = Some(quote!(::error_log::LevelFilter::#level));
+                } else {
+                    return Err(meta.error("expected `code`, `help` or `level`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(self)
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = parse2(input)?;
+    let defaults = EntryAttr::default().parse(&input.attrs)?;
+    let arms = match &input.data {
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let name = &variant.ident;
+                Ok((
+                    quote!(Self::#name { .. }),
+                    defaults.clone().parse(&variant.attrs)?,
+                ))
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Data::Struct(_) => vec![(quote!(_), defaults)],
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ErrorLogEntry can't be derived for unions",
+            ))
+        }
+    };
+    let option = |value: &Option<LitStr>| match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    };
+    let patterns = arms.iter().map(|(pattern, _)| pattern).collect::<Vec<_>>();
+    let codes = arms.iter().map(|(_, attr)| option(&attr.code));
+    let helps = arms.iter().map(|(_, attr)| option(&attr.help));
+    let levels = arms.iter().map(|(_, attr)| match &attr.level {
+        Some(level) => level.clone(),
+        None => quote!(::error_log::LevelFilter::Error),
+    });
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::error_log::ErrorLogEntry for #name #ty_generics #where_clause {
+            fn code(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#patterns => #codes,)*
+                }
+            }
+            fn help(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#patterns => #helps,)*
+                }
+            }
+            fn level(&self) -> ::error_log::LevelFilter {
+                match self {
+                    #(#patterns => #levels,)*
+                }
+            }
+        }
+    })
+}
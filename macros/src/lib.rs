@@ -0,0 +1,57 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::all, rustdoc::all, missing_docs)]
+/*!
+Procedural macros for [error_log](https://docs.rs/error_log). Use them through the `macros` feature of `error_log`.
+*/
+
+mod attribute;
+mod derive;
+
+use proc_macro::TokenStream;
+
+/**
+Wrap function body to build the returned `ErrorLog`.
+
+- An `ErrorLog` named `log` is created at the start of the function and can be used with the `log_*!` macros.
+- `res?` pushes the error of `res` and returns `log`, like [`try_add!`](https://docs.rs/error_log/latest/error_log/macro.try_add.html).
+- The final expression and values of `return` become the `ok` value.
+
+`?` inside closures, async blocks and nested items is left untouched.
+Inside macro invocations, it only gets replaced if the arguments are comma separated expressions, like for `println!("{}", res?)`.
+
+The log is created using `Default::default()`, or the constructor of the preset if the return type is
+`ErrorLogAnyhow`, `ErrorLogEyre` or `ErrorLogMiette`, e.g. `new_anyhow()`. Other type aliases of presets get the default settings.
+
+```ignore
+#[error_log]
+fn parse(input: &str) -> ErrorLog<i32, ParseIntError> {
+    let num = input.parse::<i32>()?;
+    log_info!(log, "parsed {num}");
+    num * 2
+}
+```
+*/
+#[proc_macro_attribute]
+pub fn error_log(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attribute::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/**
+Derive `ErrorLogEntry`, setting code, help text and level of errors using `#[entry(...)]` attributes.
+
+- `code = "E1001"`: stable code identifying the error
+- `help = "..."`: hint how the user can resolve the error
+- `level = "warn"`: level of the entry, one of `error` (default), `warn`, `info`, `debug`, `trace`
+
+Attributes on the type set the defaults for all variants.
+
+The level is opt-in: errors only get it after calling `use_severity()` on the `ErrorLog`, otherwise they are pushed as `Error`.
+*/
+#[proc_macro_derive(ErrorLogEntry, attributes(entry))]
+pub fn derive_error_log_entry(input: TokenStream) -> TokenStream {
+    derive::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,157 @@
+use crate::{Entry, ErrorLog};
+use core::ops::{Deref, DerefMut};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/**
+Marker of the position in the entries of an [`ErrorLog`], created by [`checkpoint()`][ErrorLog::checkpoint]
+
+Also records the error count and escalation state at that position, which get restored by [`rollback_to()`][ErrorLog::rollback_to].
+A checkpoint gets stale once entries before it might have changed, e.g. by [`clear_entries()`][ErrorLog::clear_entries],
+[`prepend_entries()`][ErrorLog::prepend_entries], [`entries_mut()`][ErrorLog::entries_mut] or rolling back to an earlier checkpoint.
+*/
+pub struct Checkpoint {
+    len: usize,
+    generation: usize,
+    error_count: usize,
+    escalated: bool,
+}
+
+impl<T, E> ErrorLog<T, E> {
+    /// Mark current position in the entries, to later [`rollback_to()`][Self::rollback_to] it
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.entries.len(),
+            generation: self.generation,
+            error_count: self.error_count,
+            escalated: self.escalated,
+        }
+    }
+    /**
+    Get entries pushed after given checkpoint
+
+    # Panics
+    When the checkpoint is stale, see [`Checkpoint`].
+    */
+    pub fn entries_since(&self, checkpoint: Checkpoint) -> &[Entry<E>] {
+        self.assert_current(checkpoint);
+        &self.entries[checkpoint.len..]
+    }
+    /**
+    Discard entries pushed after given checkpoint, restoring the error count and escalation state.
+
+    # Panics
+    When the checkpoint is stale, see [`Checkpoint`].
+    */
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> &mut Self {
+        self.assert_current(checkpoint);
+        if checkpoint.len < self.entries.len() {
+            self.invalidate_checkpoints_after(checkpoint.len);
+            self.entries.truncate(checkpoint.len);
+        }
+        self.error_count = checkpoint.error_count;
+        self.escalated = checkpoint.escalated;
+        self.check_escalation();
+        self
+    }
+    fn is_current(&self, checkpoint: Checkpoint) -> bool {
+        // `invalidations` holds generation and position of changes making later checkpoints stale, ordered by both,
+        // so the first one after the checkpoint got created has the lowest position
+        let invalidated = self
+            .invalidations
+            .iter()
+            .find(|(generation, _)| *generation > checkpoint.generation);
+        checkpoint.len <= self.entries.len()
+            && invalidated.is_none_or(|(_, len)| checkpoint.len <= *len)
+    }
+    fn assert_current(&self, checkpoint: Checkpoint) {
+        assert!(
+            self.is_current(checkpoint),
+            "stale checkpoint: entries before it were changed"
+        );
+    }
+    /// Make existing checkpoints stale, called when entries might get removed or reordered
+    pub(crate) fn invalidate_checkpoints(&mut self) {
+        self.invalidate_checkpoints_after(0);
+    }
+    /// Make existing checkpoints after given position stale
+    fn invalidate_checkpoints_after(&mut self, len: usize) {
+        self.generation += 1;
+        while self
+            .invalidations
+            .last()
+            .is_some_and(|(_, last)| *last >= len)
+        {
+            self.invalidations.pop();
+        }
+        self.invalidations.push((self.generation, len));
+    }
+    /**
+    Start transaction, discarding all entries pushed through the returned guard unless [`commit()`][Transaction::commit] is called.
+
+    Dropping the guard without committing panics if the start of the transaction got stale, see [`Checkpoint`].
+
+    ```
+    # use error_log::ErrorLog;
+    # #[cfg(feature = "errors")] {
+    let mut err_log = ErrorLog::<(), String>::new();
+    {
+        let mut transaction = err_log.transaction();
+        transaction.push_err("attempt failed");
+    }
+    assert!(err_log.entries().is_empty());
+    # }
+    ```
+    */
+    pub fn transaction(&mut self) -> Transaction<'_, T, E> {
+        Transaction {
+            checkpoint: self.checkpoint(),
+            log: self,
+            committed: false,
+        }
+    }
+}
+
+/// Guard discarding entries pushed during its lifetime when dropped without [`commit()`][Self::commit], created by [`ErrorLog::transaction()`]
+pub struct Transaction<'a, T, E> {
+    log: &'a mut ErrorLog<T, E>,
+    checkpoint: Checkpoint,
+    committed: bool,
+}
+
+impl<T, E> Transaction<'_, T, E> {
+    /// Keep entries pushed during the transaction
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+    /// Discard entries pushed during the transaction
+    pub fn rollback(self) {}
+    /// Get entries pushed during the transaction
+    pub fn entries_since_start(&self) -> &[Entry<E>] {
+        self.log.entries_since(self.checkpoint)
+    }
+}
+
+impl<T, E> Deref for Transaction<'_, T, E> {
+    type Target = ErrorLog<T, E>;
+    fn deref(&self) -> &Self::Target {
+        self.log
+    }
+}
+
+impl<T, E> DerefMut for Transaction<'_, T, E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.log
+    }
+}
+
+impl<T, E> Drop for Transaction<'_, T, E> {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        let panicking = std::thread::panicking();
+        #[cfg(not(feature = "std"))]
+        let panicking = false;
+        if !self.committed && (!panicking || self.log.is_current(self.checkpoint)) {
+            self.log.rollback_to(self.checkpoint);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{now, Entry, ErrorLog, GroupBy};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Debug, Display};
+
+impl<T, E> ErrorLog<T, E> {
+    /**
+    Create log for a subsystem with the given name, using the same settings.
+
+    When merged back using [`append_entries()`][Self::append_entries] or [`prepend_entries()`][Self::prepend_entries],
+    the name gets prepended to the [`origin`][Entry::origin] of its entries, e.g. `plugins/markdown` for a child `markdown` of a child `plugins`.
+    */
+    pub fn child<U>(&self, name: impl Into<String>) -> ErrorLog<U, E> {
+        let mut child = ErrorLog::new();
+        child.format_mode = self.format_mode.clone();
+        #[cfg(feature = "instant-display")]
+        {
+            child.instant_display = self.instant_display;
+        }
+        child.delimiter = self.delimiter.clone();
+        child.join = self.join;
+        child.max_level = self.max_level;
+        child.sink = self.sink.clone();
+        #[cfg(feature = "std")]
+        {
+            child.prompt = self.prompt.clone();
+            child.exit_code_fn = self.exit_code_fn.clone();
+        }
+        #[cfg(feature = "axum")]
+        {
+            child.status_fn = self.status_fn.clone();
+        }
+        child.timestamp_format = self.timestamp_format.clone();
+        child.created_at = now();
+        child.error_level = self.error_level.clone();
+        child.entry_details = self.entry_details;
+        child.error_type_fn = self.error_type_fn.clone();
+        child.deny_warnings = self.deny_warnings;
+        child.group_by = self.group_by;
+        child.max_per_group = self.max_per_group;
+        child.origin = Some(name.into());
+        child
+    }
+    /// Get name given to [`child()`][Self::child]
+    pub fn get_origin(&self) -> Option<&str> {
+        self.origin.as_deref()
+    }
+    /// Get entries originating from the child log with the given path or its children
+    pub fn entries_from<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a Entry<E>> + 'a {
+        self.entries.iter().filter(move |entry| {
+            entry.origin.as_deref().is_some_and(|origin| {
+                origin == path
+                    || origin
+                        .strip_prefix(path)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+        })
+    }
+    /// Get entries grouped by [`origin`][Entry::origin], in order of their first appearance
+    pub fn group_by_origin(&self) -> Vec<(Option<&str>, Vec<&Entry<E>>)> {
+        let mut groups: Vec<(Option<&str>, Vec<&Entry<E>>)> = Vec::new();
+        for entry in &self.entries {
+            let origin = entry.origin.as_deref();
+            match groups.iter_mut().find(|(group, _)| *group == origin) {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((origin, Vec::from([entry]))),
+            }
+        }
+        groups
+    }
+    /// Prepend name of this log to the origin of its entries before they get merged into another log
+    pub(crate) fn label_origin(&mut self) {
+        let Some(name) = &self.origin else {
+            return;
+        };
+        for entry in &mut self.entries {
+            entry.origin = Some(match &entry.origin {
+                Some(origin) => format!("{name}/{origin}"),
+                None => name.clone(),
+            });
+        }
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /// Render entries grouped by [`origin`][Entry::origin], one section per child log, see [`render_grouped()`][Self::render_grouped] with [`GroupBy::Origin`]
+    pub fn render_by_origin(&self) -> String {
+        self.render_grouped(GroupBy::Origin)
+    }
+}
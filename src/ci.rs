@@ -0,0 +1,110 @@
+use crate::{escape_html, Entry, ErrorLog, FormatMode};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Debug, Display};
+use log::LevelFilter;
+
+/// Whether the character is allowed in XML 1.0 documents
+fn is_xml_char(c: char) -> bool {
+    !matches!(c, '\0'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\u{FFFE}' | '\u{FFFF}')
+}
+
+/// Escape string for usage inside XML, removing characters not allowed in XML 1.0
+fn escape_xml(input: &str) -> String {
+    let valid: String = input.chars().filter(|c| is_xml_char(*c)).collect();
+    escape_html(&valid)
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /// Entries not filtered by the [`max_level`][Self::max_level] and their message
+    fn ci_entries(&self) -> Vec<(&Entry<E>, String)> {
+        self.mark_displayed();
+        self.entries()
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .get_message_filter(self.get_format_mode(), &self.max_level)
+                    .map(|msg| (entry, msg))
+            })
+            .collect()
+    }
+    /**
+    Render [`Entries`][crate::Entries] as JUnit XML test suite with the given name.
+
+    Every entry becomes a test case named after the first line of its message:
+    - errors are failures containing their [`FormatMode::PrettyDebug`] output
+    - warnings are skipped
+    - all other messages pass
+    */
+    pub fn render_junit(&self, suite: &str) -> String {
+        let entries = self.ci_entries();
+        let count = |level| {
+            entries
+                .iter()
+                .filter(|(e, _)| e.get_level() == level)
+                .count()
+        };
+        let suite = escape_xml(suite);
+        let mut out = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites>\n<testsuite name=\"{suite}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">\n",
+            entries.len(),
+            count(LevelFilter::Error),
+            count(LevelFilter::Warn),
+        );
+        for (entry, msg) in &entries {
+            let name = escape_xml(msg.lines().next().unwrap_or_default());
+            out.push_str(&format!("<testcase classname=\"{suite}\" name=\"{name}\""));
+            match entry.get_level() {
+                LevelFilter::Error => out.push_str(&format!(
+                    ">\n<failure message=\"{}\">{}</failure>\n</testcase>\n",
+                    escape_xml(msg),
+                    escape_xml(&entry.get_message(&FormatMode::PrettyDebug))
+                )),
+                LevelFilter::Warn => out.push_str(&format!(
+                    ">\n<skipped message=\"{}\"/>\n</testcase>\n",
+                    escape_xml(msg)
+                )),
+                _ => out.push_str("/>\n"),
+            }
+        }
+        out.push_str("</testsuite>\n</testsuites>\n");
+        out
+    }
+    /**
+    Render [`Entries`][crate::Entries] as [TAP](https://testanything.org) version 13 stream.
+
+    Every entry becomes a test point described by the first line of its message:
+    - errors are `not ok`, followed by a YAML block containing their [`FormatMode::PrettyDebug`] output
+    - warnings are `ok` with a `SKIP` directive
+    - all other messages are `ok`
+    */
+    pub fn render_tap(&self) -> String {
+        let entries = self.ci_entries();
+        let mut out = format!("TAP version 13\n1..{}\n", entries.len());
+        for (i, (entry, msg)) in entries.iter().enumerate() {
+            let description = msg
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .replace('\\', "\\\\")
+                .replace('#', "\\#");
+            let n = i + 1;
+            match entry.get_level() {
+                LevelFilter::Error => {
+                    out.push_str(&format!(
+                        "not ok {n} - {description}\n  ---\n  message: |\n"
+                    ));
+                    for line in entry.get_message(&FormatMode::PrettyDebug).lines() {
+                        out.push_str(&format!("    {line}\n"));
+                    }
+                    out.push_str("  ...\n");
+                }
+                LevelFilter::Warn => {
+                    out.push_str(&format!("ok {n} - {description} # SKIP warning\n"))
+                }
+                _ => out.push_str(&format!("ok {n} - {description}\n")),
+            }
+        }
+        out
+    }
+}
@@ -1,11 +1,21 @@
 #[cfg(doc)]
 use crate::Entries;
-use crate::{now, print, ErrorLog, FormatMode};
-use alloc::string::String;
+use crate::{now, print, Entry, EntryContent, ErrorLog, FormatMode, TimestampFormat};
+use alloc::{format, string::String, vec::Vec};
 use core::fmt::{Debug, Display};
 use log::LevelFilter;
 
 impl<T, E> ErrorLog<T, E> {
+    /// Get code and help of an error entry, set by [`use_entry_details()`][Self::use_entry_details]
+    pub(crate) fn entry_details(
+        &self,
+        entry: &Entry<E>,
+    ) -> (Option<&'static str>, Option<&'static str>) {
+        match (&entry.content, self.entry_details) {
+            (EntryContent::Error(err), Some(fun)) => fun(err),
+            _ => (None, None),
+        }
+    }
     /// Reset delimiter to default value.
     pub fn clear_delimiter(&mut self) -> &mut Self {
         self.delimiter = "".into();
@@ -23,6 +33,10 @@ impl<T, E> ErrorLog<T, E> {
         self.format_mode = mode;
         self
     }
+    /// Format unix timestamp using the [`TimestampFormat`] of this instance
+    pub fn format_timestamp(&self, unix: i64) -> String {
+        self.timestamp_format.format(unix, self.created_at)
+    }
     /// Get [`FormatMode`]
     pub fn get_format_mode(&self) -> &FormatMode {
         &self.format_mode
@@ -37,10 +51,25 @@ impl<T, E> ErrorLog<T, E> {
     fn get_level(&self, i: usize) -> LevelFilter {
         self.entries[i].get_level()
     }
+    /// Get highest level of the entries that get displayed.
+    /// Returns [`LevelFilter::Error`] if there are no entries
+    fn highest_level(&self) -> LevelFilter {
+        self.entries
+            .iter()
+            .filter(|e| e.is_shown(&self.max_level))
+            .map(Entry::get_level)
+            .filter(|level| *level != LevelFilter::Off)
+            .min()
+            .unwrap_or(LevelFilter::Error)
+    }
     /// Get timestamp of given index
     fn get_timestamp(&self, i: usize) -> i64 {
         self.entries[i].timestamp
     }
+    /// Get [`TimestampFormat`]
+    pub fn get_timestamp_format(&self) -> &TimestampFormat {
+        &self.timestamp_format
+    }
     #[cfg(feature = "instant-display")]
     /// Set weightier the function display incoming entries instantly.
     /// Note: Entries wont get stored when true
@@ -48,6 +77,11 @@ impl<T, E> ErrorLog<T, E> {
         self.instant_display = val;
         self
     }
+    /// Set how timestamps get formatted when displaying [`Entries`]. Default: [`TimestampFormat::Rfc3339`]
+    pub fn timestamp_format(&mut self, format: TimestampFormat) -> &mut Self {
+        self.timestamp_format = format;
+        self
+    }
     /// Set weightier to join [`Entries`] into one big String when displaying them.
     pub fn join_on_display(&mut self, join: bool) -> &mut Self {
         self.join = join;
@@ -56,8 +90,14 @@ impl<T, E> ErrorLog<T, E> {
 }
 
 impl<T, E: Display + Debug> ErrorLog<T, E> {
-    /// Get String of Combined [`Entries`]
+    /// Get String of Combined [`Entries`], grouped using [`render_grouped()`][Self::render_grouped] if [`group_by()`][Self::group_by] is set
     pub fn join_to_string(&self) -> Option<String> {
+        if let Some(group) = self.group_by {
+            return match self.entries.iter().any(|e| e.is_shown(&self.max_level)) {
+                true => Some(self.render_grouped(group)),
+                false => None,
+            };
+        }
         let mut out = String::from("");
         for i in 0..self.entries.len() {
             if let Some(msg) = self.get_string(i) {
@@ -69,13 +109,18 @@ impl<T, E: Display + Debug> ErrorLog<T, E> {
             false => Some(out),
         }
     }
+    /// Pass entry to display function
+    fn display_entry(&self, level: LevelFilter, unix: i64, msg: String) {
+        self.sink.0.display(level, self.format_timestamp(unix), msg);
+    }
     /// Displays all [`Entries`]
     pub(crate) fn display_helper(&self) {
         match self.join {
             false => {
                 for i in 0..self.entries.len() {
+                    self.entries[i].displayed.replace(true);
                     if let Some(msg) = self.get_string(i) {
-                        (self.display_fn)(self.get_level(i), self.get_timestamp(i), msg);
+                        self.display_entry(self.get_level(i), self.get_timestamp(i), msg);
                     }
                 }
                 if !self.delimiter.is_empty() {
@@ -83,11 +128,42 @@ impl<T, E: Display + Debug> ErrorLog<T, E> {
                 }
             }
             true => {
+                self.mark_displayed();
                 if let Some(err) = self.join_to_string() {
-                    (self.display_fn)(LevelFilter::Error, now(), err)
+                    self.display_entry(self.highest_level(), now(), err)
                 }
             }
         }
+        self.sink.0.flush();
+    }
+    /// Display entries that weren't displayed yet by a `display_*` call or the instant display
+    pub fn display_undisplayed(&self) {
+        let mut joined = String::new();
+        let mut levels = Vec::new();
+        for i in 0..self.entries.len() {
+            if self.entries[i].displayed.replace(true) {
+                continue;
+            }
+            let Some(msg) = self.get_string(i) else {
+                continue;
+            };
+            match self.join {
+                false => self.display_entry(self.get_level(i), self.get_timestamp(i), msg),
+                true => {
+                    joined.push_str(&(msg + &self.delimiter));
+                    levels.push(self.get_level(i));
+                }
+            }
+        }
+        if !joined.is_empty() {
+            let level = levels
+                .into_iter()
+                .filter(|level| *level != LevelFilter::Off)
+                .min()
+                .unwrap_or(LevelFilter::Error);
+            self.display_entry(level, now(), joined);
+        }
+        self.sink.0.flush();
     }
     /// Displays [`Entries`] and returns [`Option`] to mutable reference of `ok` value
     pub fn display_mut(&mut self) -> Option<&mut T> {
@@ -95,9 +171,9 @@ impl<T, E: Display + Debug> ErrorLog<T, E> {
         self.ok.as_mut()
     }
     /// Displays [`Entries`] and returns `ok` value as [`Option`]
-    pub fn display_ok(self) -> Option<T> {
+    pub fn display_ok(mut self) -> Option<T> {
         self.display_helper();
-        self.ok
+        self.ok.take()
     }
     /// Displays [`Entries`] and returns [`Option`] to reference of `ok` value
     pub fn display_ref(&self) -> Option<&T> {
@@ -128,23 +204,36 @@ impl<T, E: Display + Debug> ErrorLog<T, E> {
         self.display_ok().unwrap_or(or)
     }
     /// Display entries and get `ok` value, using default value if no value set
-    pub fn display_unwrap_or_default(self) -> T
+    pub fn display_unwrap_or_default(mut self) -> T
     where
         T: Default,
     {
         self.display_helper();
-        self.ok.unwrap_or_default()
+        self.ok.take().unwrap_or_default()
     }
     /// Display entries and get `ok` value, using value calculated from given closure
     ///
     /// Related: [`display_unwrap_or()`][Self::display_unwrap_or]
-    pub fn display_unwrap_or_else(self, run: impl FnOnce() -> T) -> T {
+    pub fn display_unwrap_or_else(mut self, run: impl FnOnce() -> T) -> T {
         self.display_helper();
-        self.ok.unwrap_or_else(run)
+        self.ok.take().unwrap_or_else(run)
     }
     /// get (error) message of entry at given index
     fn get_string(&self, i: usize) -> Option<String> {
-        self.entries[i].get_message_filter(self.get_format_mode(), &self.max_level)
+        self.entry_message(&self.entries[i])
+    }
+    /// Get message of entry with its span, code and help, or `None` if it is filtered
+    pub(crate) fn entry_message(&self, entry: &Entry<E>) -> Option<String> {
+        let msg = entry.get_message_span(self.get_format_mode(), &self.max_level)?;
+        let (code, help) = self.entry_details(entry);
+        let mut out = match code {
+            Some(code) => format!("[{code}] {msg}"),
+            None => msg,
+        };
+        if let Some(help) = help {
+            out.push_str(&format!("\nhelp: {help}"));
+        }
+        Some(out)
     }
     #[cfg(feature = "instant-display")]
     /// Displays all [`crate::Entries`]
@@ -153,20 +242,21 @@ impl<T, E: Display + Debug> ErrorLog<T, E> {
             true => {
                 for i in 0..self.entries.len() {
                     if let Some(msg) = self.instant_get_string(i) {
-                        (self.display_fn)(self.get_level(i), self.get_timestamp(i), msg);
+                        self.display_entry(self.get_level(i), self.get_timestamp(i), msg);
                     }
                 }
             }
             false => {
                 if let Some(err) = self.join_to_string() {
-                    (self.display_fn)(LevelFilter::Error, now(), err)
+                    self.display_entry(self.highest_level(), now(), err)
                 }
             }
         }
+        self.sink.0.flush();
     }
     #[cfg(feature = "instant-display")]
     fn instant_get_string(&self, i: usize) -> Option<String> {
-        let displayed = &self.entries[i].instant_display_displayed;
+        let displayed = &self.entries[i].displayed;
         if *displayed.borrow() {
             None
         } else {
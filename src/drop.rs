@@ -0,0 +1,106 @@
+use crate::ErrorLog;
+use core::fmt::{self, Debug, Display};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/**
+What happens when an [`ErrorLog`] gets dropped with entries that weren't displayed.
+
+Entries count as displayed once passed to a `display_*` function or rendered, e.g. by [`render_html()`][ErrorLog::render_html],
+the `ErrorLogWidget` or when responding with the log.
+*/
+pub enum DropPolicy {
+    /// Drop entries silently (Default)
+    #[default]
+    Ignore,
+    /// Display the entries, see [`display_undisplayed()`][ErrorLog::display_undisplayed]
+    DisplayUndisplayed,
+    /// Panic in debug builds, display the entries in release builds
+    DebugAssertPanic,
+}
+
+/// [`DropPolicy`] of an [`ErrorLog`]. Reset when cloned, so the entries get handled by one instance only
+pub(crate) struct OnDrop<T, E> {
+    policy: DropPolicy,
+    display: Option<fn(&ErrorLog<T, E>)>,
+}
+
+impl<T, E> Default for OnDrop<T, E> {
+    fn default() -> Self {
+        Self {
+            policy: DropPolicy::Ignore,
+            display: None,
+        }
+    }
+}
+
+impl<T, E> Clone for OnDrop<T, E> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<T, E> Debug for OnDrop<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.policy.fmt(f)
+    }
+}
+
+impl<T, E> ErrorLog<T, E> {
+    /// Get [`DropPolicy`]
+    pub fn get_drop_policy(&self) -> DropPolicy {
+        self.on_drop.policy
+    }
+    /// Get number of entries that weren't displayed yet, excluding entries filtered by the [`max_level`][Self::max_level]
+    pub fn undisplayed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| !*entry.displayed.borrow() && entry.is_shown(&self.max_level))
+            .count()
+    }
+    /// Mark all entries as displayed
+    pub(crate) fn mark_displayed(&self) {
+        for entry in &self.entries {
+            entry.displayed.replace(true);
+        }
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Set what happens when the log gets dropped with entries that weren't displayed. Default: [`DropPolicy::Ignore`]
+
+    Clones start with [`DropPolicy::Ignore`], so entries don't get displayed once per clone.
+    */
+    pub fn drop_policy(&mut self, policy: DropPolicy) -> &mut Self {
+        self.on_drop = OnDrop {
+            policy,
+            display: Some(Self::display_undisplayed),
+        };
+        self
+    }
+}
+
+impl<T, E> Drop for ErrorLog<T, E> {
+    fn drop(&mut self) {
+        if self.on_drop.policy == DropPolicy::Ignore {
+            return;
+        }
+        let count = self.undisplayed_count();
+        if count == 0 {
+            return;
+        }
+        #[cfg(feature = "std")]
+        let panicking = std::thread::panicking();
+        #[cfg(not(feature = "std"))]
+        let panicking = false;
+        if self.on_drop.policy == DropPolicy::DebugAssertPanic
+            && cfg!(debug_assertions)
+            && !panicking
+        {
+            panic!("ErrorLog dropped with {count} undisplayed entries");
+        }
+        if let Some(display) = self.on_drop.display {
+            display(self);
+        }
+    }
+}
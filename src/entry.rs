@@ -1,7 +1,8 @@
-use crate::{now, FormatMode};
+use crate::{now, FormatMode, Span};
 use alloc::vec::Vec;
 use alloc::{format, string::String};
 use core::cell::RefCell;
+use core::cmp::Ordering;
 use core::fmt::{Debug, Display};
 use log::LevelFilter;
 
@@ -25,14 +26,23 @@ impl<E> EntriesExt for Entries<E> {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone)]
-/// Entry containing an Error of type `E` or an log message
+#[derive(Debug, Clone)]
+/**
+Entry containing an Error of type `E` or an log message
+
+Entries are compared by their fields, but not by whether they were displayed already.
+*/
 pub struct Entry<E> {
     /// Content of the entry
     pub content: EntryContent<E>,
     /// Timestamp when the event occurred
     pub timestamp: i64,
-    pub(crate) instant_display_displayed: RefCell<bool>,
+    /// Location in a source text the entry relates to
+    pub span: Option<Span>,
+    /// Path of the [`child()`][crate::ErrorLog::child] logs the entry was merged from, e.g. `plugins/markdown`
+    pub origin: Option<String>,
+    pub(crate) error_level: LevelFilter,
+    pub(crate) displayed: RefCell<bool>,
 }
 #[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone)]
 /// The Content of the Entry
@@ -48,12 +58,53 @@ pub enum EntryContent<E> {
     },
 }
 
+impl<E> Entry<E> {
+    /// Get fields used for comparison, leaving out whether the entry was displayed
+    fn compared(
+        &self,
+    ) -> (
+        &EntryContent<E>,
+        i64,
+        &Option<Span>,
+        &Option<String>,
+        LevelFilter,
+    ) {
+        (
+            &self.content,
+            self.timestamp,
+            &self.span,
+            &self.origin,
+            self.error_level,
+        )
+    }
+}
+
+impl<E: PartialEq> PartialEq for Entry<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.compared() == other.compared()
+    }
+}
+
+impl<E: Eq> Eq for Entry<E> {}
+
+impl<E: PartialOrd> PartialOrd for Entry<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compared().partial_cmp(&other.compared())
+    }
+}
+
+impl<E: Ord> Ord for Entry<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compared().cmp(&other.compared())
+    }
+}
+
 impl<E> Entry<E> {
     /// Get level
-    /// Returns [`LevelFilter::Error`] or the level of the Message
+    /// Returns the level of the error ([`LevelFilter::Error`] by default) or the level of the Message
     pub fn get_level(&self) -> LevelFilter {
         match self.content {
-            EntryContent::Error(_) => LevelFilter::Error,
+            EntryContent::Error(_) => self.error_level,
             EntryContent::Message { level, .. } => level,
         }
     }
@@ -61,19 +112,58 @@ impl<E> Entry<E> {
         Self {
             content,
             timestamp: now(),
-            instant_display_displayed: RefCell::new(false),
+            span: None,
+            origin: None,
+            error_level: LevelFilter::Error,
+            displayed: RefCell::new(false),
         }
     }
     /// Create entry of type error
     pub fn new_error(err: E) -> Self {
         Self::new(EntryContent::Error(err))
     }
+    /// Create entry of type error with given level
+    pub fn new_error_with_level(err: E, level: LevelFilter) -> Self {
+        Self {
+            error_level: level,
+            ..Self::new_error(err)
+        }
+    }
     /// Create Entry of type Error
     pub fn new_message(level: LevelFilter, message: String) -> Self {
         Self::new(EntryContent::Message { level, message })
     }
+    /// Check whether entry gets shown with given max [`LevelFilter`].
+    /// Errors with level [`LevelFilter::Error`] always get shown
+    pub fn is_shown(&self, max_level: &LevelFilter) -> bool {
+        let level = self.get_level();
+        match self.content {
+            EntryContent::Error(_) if level == LevelFilter::Error => true,
+            _ => level <= *max_level,
+        }
+    }
+    /// Change level of entry
+    pub(crate) fn set_level(&mut self, new: LevelFilter) {
+        match &mut self.content {
+            EntryContent::Error(_) => self.error_level = new,
+            EntryContent::Message { level, .. } => *level = new,
+        }
+    }
+    /// Attach [`Span`] to entry
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
 }
 impl<E: Debug + Display> Entry<E> {
+    /// Get message followed by the rendered [`Span`], if any
+    pub fn get_message_span(&self, mode: &FormatMode, max_level: &LevelFilter) -> Option<String> {
+        let msg = self.get_message_filter(mode, max_level)?;
+        Some(match &self.span {
+            Some(span) => format!("{msg}\n{}", span.render()),
+            None => msg,
+        })
+    }
     /// Get message as [`String`]
     pub fn get_message(&self, mode: &FormatMode) -> String {
         match self.get_message_filter(mode, &LevelFilter::Trace) {
@@ -83,20 +173,17 @@ impl<E: Debug + Display> Entry<E> {
     }
     /// Get Message as String filtering based on given [`LevelFilter`]
     pub fn get_message_filter(&self, mode: &FormatMode, max_level: &LevelFilter) -> Option<String> {
-        match &self.content {
-            EntryContent::Error(err) => Some(match mode {
+        if !self.is_shown(max_level) {
+            return None;
+        }
+        Some(match &self.content {
+            EntryContent::Error(err) => match mode {
                 FormatMode::Normal => format!("{err}"),
                 FormatMode::Debug => format!("{err:?}"),
                 FormatMode::PrettyDebug => format!("{err:#?}"),
-            }),
-            EntryContent::Message {
-                message: msg,
-                level,
-            } => match level <= max_level {
-                true => Some(msg.clone()),
-                false => None,
             },
-        }
+            EntryContent::Message { message, .. } => message.clone(),
+        })
     }
 }
 
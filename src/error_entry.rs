@@ -0,0 +1,60 @@
+#![cfg(feature = "errors")]
+use crate::ErrorLog;
+use log::LevelFilter;
+
+/**
+Additional information about an error shown to the user.
+
+Can be derived using `#[derive(ErrorLogEntry)]` (requires `macros` feature):
+```ignore
+#[derive(Debug, thiserror::Error, ErrorLogEntry)]
+#[entry(level = "error")]
+enum ConfigError {
+    #[error("config file not found")]
+    #[entry(code = "E1001", help = "run `app init` to create one")]
+    NotFound,
+    #[error("unknown key {0}")]
+    #[entry(code = "W1002", level = "warn")]
+    UnknownKey(String),
+}
+```
+Attributes on the type set the defaults for all variants.
+
+The level is only used after calling [`use_severity()`][crate::ErrorLog::use_severity] on the [`ErrorLog`][crate::ErrorLog], otherwise errors get [`LevelFilter::Error`].
+Likewise, code and help are only shown after calling [`use_entry_details()`][crate::ErrorLog::use_entry_details].
+*/
+pub trait ErrorLogEntry {
+    /// Stable code identifying the error, e.g. `E1001`
+    fn code(&self) -> Option<&'static str> {
+        None
+    }
+    /// Hint how the user can resolve the error
+    fn help(&self) -> Option<&'static str> {
+        None
+    }
+    /// Level of the entry, used as [`Severity`][crate::Severity]. Default: [`LevelFilter::Error`]
+    fn level(&self) -> LevelFilter {
+        LevelFilter::Error
+    }
+}
+
+impl<T, E: ErrorLogEntry> ErrorLog<T, E> {
+    /**
+    Show [`code()`][ErrorLogEntry::code] and [`help()`][ErrorLogEntry::help] of errors when displaying or rendering them:
+    ```text
+    [E1001] config file not found
+    help: run `app init` to create one
+    ```
+    */
+    pub fn use_entry_details(&mut self) -> &mut Self {
+        self.entry_details = Some(|err: &E| (err.code(), err.help()));
+        self
+    }
+}
+
+impl<T, E: ErrorLogEntry + 'static> ErrorLog<T, E> {
+    /// Use [`ErrorLogEntry::code()`] as section of errors when grouping by [`GroupBy::ErrorType`][crate::GroupBy::ErrorType], see [`error_type_fn()`][ErrorLog::error_type_fn]
+    pub fn use_error_codes(&mut self) -> &mut Self {
+        self.error_type_fn(|err| err.code().map(Into::into))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Entry, ErrorLog};
+use log::LevelFilter;
+
+impl<T, E> ErrorLog<T, E> {
+    /**
+    Treat warnings as errors: warnings pushed afterwards get the level [`LevelFilter::Error`] and trigger the escalation.
+
+    See [`is_escalated()`][Self::is_escalated]
+    */
+    pub fn deny_warnings(&mut self, deny: bool) -> &mut Self {
+        self.deny_warnings = deny;
+        self
+    }
+    /**
+    Trigger the escalation once given number of errors got pushed.
+
+    See [`is_escalated()`][Self::is_escalated]
+    */
+    pub fn max_errors(&mut self, max: usize) -> &mut Self {
+        self.max_errors = Some(max);
+        self.check_escalation();
+        self
+    }
+    /// Get number of pushed entries with level [`LevelFilter::Error`], including promoted warnings
+    pub fn error_count(&self) -> usize {
+        self.error_count
+    }
+    /**
+    Check whether the escalation got triggered, either by a warning when [`deny_warnings()`][Self::deny_warnings] is set,
+    or by reaching the [`max_errors()`][Self::max_errors].
+
+    Once triggered, the [`Termination`][std::process::Termination] impl reports failure and [`try_push!`][crate::try_push] returns.
+    */
+    pub fn is_escalated(&self) -> bool {
+        self.escalated
+    }
+    /// Push entry, applying the escalation policy
+    #[cfg(any(feature = "errors", feature = "messages"))]
+    pub(crate) fn push_entry(&mut self, mut entry: Entry<E>) {
+        self.count_entry(&mut entry);
+        self.entries.push(entry);
+        self.check_escalation();
+    }
+    /// Apply the escalation policy to entries merged from another log
+    pub(crate) fn count_entries(&mut self, entries: &mut [Entry<E>]) {
+        for entry in entries {
+            self.count_entry(entry);
+        }
+        self.check_escalation();
+    }
+    /// Promote denied warnings and count errors of an entry about to be added
+    fn count_entry(&mut self, entry: &mut Entry<E>) {
+        if self.deny_warnings && entry.get_level() == LevelFilter::Warn {
+            entry.set_level(LevelFilter::Error);
+            self.escalated = true;
+        }
+        if entry.get_level() == LevelFilter::Error {
+            self.error_count += 1;
+        }
+    }
+    pub(crate) fn check_escalation(&mut self) {
+        if matches!(self.max_errors, Some(max) if self.error_count >= max) {
+            self.escalated = true;
+        }
+    }
+}
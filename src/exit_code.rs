@@ -0,0 +1,42 @@
+#![cfg(feature = "std")]
+use crate::{Entries, ErrorLog, Shared};
+use log::LevelFilter;
+use std::sync::Arc;
+
+impl<T, E> ErrorLog<T, E> {
+    /**
+    Set function choosing the exit code reported when returning the log from `main`.
+
+    The function gets the unfiltered entries. When the escalation got triggered, at least `1` is reported.
+    Default: `0` if an `ok` value is set, otherwise `1`
+    */
+    pub fn exit_code_fn(
+        &mut self,
+        fun: impl Fn(&Entries<E>) -> u8 + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.exit_code_fn = Some(Shared(Arc::new(fun)));
+        self
+    }
+    /// Report exit code `errors` if there are entries with level [`LevelFilter::Error`], `warnings` if there are warnings, otherwise `0`
+    pub fn exit_code_by_level(&mut self, errors: u8, warnings: u8) -> &mut Self {
+        self.exit_code_fn(move |entries| {
+            let has = |level| entries.iter().any(|entry| entry.get_level() == level);
+            match (has(LevelFilter::Error), has(LevelFilter::Warn)) {
+                (true, _) => errors,
+                (false, true) => warnings,
+                (false, false) => 0,
+            }
+        })
+    }
+    /// Get exit code reported when returning the log from `main`, see [`exit_code_fn()`][Self::exit_code_fn]
+    pub fn exit_code(&self) -> u8 {
+        let code = match &self.exit_code_fn {
+            Some(fun) => (fun.0)(&self.entries),
+            None => self.ok.is_none().into(),
+        };
+        match self.escalated {
+            true => code.max(1),
+            false => code,
+        }
+    }
+}
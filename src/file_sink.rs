@@ -0,0 +1,184 @@
+#![cfg(feature = "std")]
+use crate::{escape_json, println_sink, with_timestamp, Sink};
+use log::LevelFilter;
+use std::{
+    ffi::OsString,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// How entries are written to the file of a [`FileSink`]
+pub enum FileFormat {
+    /// One line per entry, formatted like the default [`println`] output (Default)
+    #[default]
+    Text,
+    /// One JSON object per line with the keys `level`, `timestamp` and `message`
+    JsonLines,
+}
+
+/**
+[`Sink`] appending entries to a file.
+
+- The file gets created when the first entry is written.
+- When [`max_size`][Self::max_size] is reached, the file gets rotated: `errors.log` is renamed to `errors.log.1`, `errors.log.1` to `errors.log.2` and so on, keeping up to [`keep`][Self::keep] old files.
+- Written entries get flushed at the end of every `display_*` call and when the sink is dropped.
+- If writing fails, the error and the entry are passed to the [`fallback`][Self::fallback] sink (Default: [`println`]).
+*/
+pub struct FileSink {
+    path: PathBuf,
+    format: FileFormat,
+    max_size: Option<u64>,
+    keep: usize,
+    fallback: Arc<dyn Sink>,
+    writer: Mutex<Option<SizedWriter>>,
+}
+
+struct SizedWriter {
+    file: BufWriter<File>,
+    size: u64,
+}
+
+impl FileSink {
+    /// Create sink appending plain text to the file at the given path
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            format: FileFormat::default(),
+            max_size: None,
+            keep: 0,
+            fallback: Arc::new(println_sink as fn(LevelFilter, String, String)),
+            writer: Mutex::new(None),
+        }
+    }
+    /// Set how entries get written. Default: [`FileFormat::Text`]
+    pub fn format(&mut self, format: FileFormat) -> &mut Self {
+        self.format = format;
+        self
+    }
+    /// Rotate file when it would exceed given size in bytes. Default: never rotate
+    pub fn max_size(&mut self, bytes: u64) -> &mut Self {
+        self.max_size = Some(bytes);
+        self
+    }
+    /// Set how many rotated files are kept. Default: 0
+    pub fn keep(&mut self, count: usize) -> &mut Self {
+        self.keep = count;
+        self
+    }
+    /// Set sink receiving write failures and the entries that couldn't be written
+    pub fn fallback(&mut self, sink: impl Sink + 'static) -> &mut Self {
+        self.fallback = Arc::new(sink);
+        self
+    }
+    /// Get path of the current file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    fn render(&self, level: LevelFilter, timestamp: &str, message: &str) -> String {
+        match self.format {
+            FileFormat::Text => format!("{level} {}\n", with_timestamp(timestamp, message)),
+            FileFormat::JsonLines => format!(
+                "{{\"level\":\"{level}\",\"timestamp\":\"{}\",\"message\":\"{}\"}}\n",
+                escape_json(timestamp),
+                escape_json(message)
+            ),
+        }
+    }
+    fn write(&self, line: &str) -> io::Result<()> {
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        // Opening reads the size of an existing file, which may already exceed the limit
+        let mut current = match writer.take() {
+            Some(current) => current,
+            None => self.open()?,
+        };
+        if let Some(max) = self.max_size {
+            if current.size > 0 && current.size + line.len() as u64 > max {
+                current.file.flush()?;
+                drop(current);
+                self.rotate()?;
+                current = self.open()?;
+            }
+        }
+        current.file.write_all(line.as_bytes())?;
+        current.size += line.len() as u64;
+        *writer = Some(current);
+        Ok(())
+    }
+    fn open(&self) -> io::Result<SizedWriter> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let size = file.metadata()?.len();
+        Ok(SizedWriter {
+            file: BufWriter::new(file),
+            size,
+        })
+    }
+    /// Path of the rotated file with the given index
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = OsString::from(self.path.as_os_str());
+        path.push(format!(".{index}"));
+        path.into()
+    }
+    fn rotate(&self) -> io::Result<()> {
+        if self.keep == 0 {
+            return fs::remove_file(&self.path);
+        }
+        let oldest = self.rotated_path(self.keep);
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+        for i in (1..self.keep).rev() {
+            let from = self.rotated_path(i);
+            if from.exists() {
+                fs::rename(from, self.rotated_path(i + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))
+    }
+    fn flush_writer(&self) -> io::Result<()> {
+        match self
+            .writer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            Some(current) => current.file.flush(),
+            None => Ok(()),
+        }
+    }
+    fn report(&self, err: io::Error) {
+        self.fallback.display(
+            LevelFilter::Error,
+            String::new(),
+            format!("Failed to write to {}: {err}", self.path.display()),
+        );
+    }
+}
+
+impl Sink for FileSink {
+    fn display(&self, level: LevelFilter, timestamp: String, message: String) {
+        if let Err(err) = self.write(&self.render(level, &timestamp, &message)) {
+            self.report(err);
+            self.fallback.display(level, timestamp, message);
+        }
+    }
+    fn flush(&self) {
+        if let Err(err) = self.flush_writer() {
+            self.report(err);
+        }
+        self.fallback.flush();
+    }
+}
+
+impl Drop for FileSink {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush_writer() {
+            self.report(err);
+        }
+    }
+}
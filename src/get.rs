@@ -10,10 +10,12 @@ impl<T, E> ErrorLog<T, E> {
     /// Get mutable reference to Vector of [`Entries`]
     /// NOTE: Does not filter entries lower than maximum [`LevelFilter`][crate::LevelFilter]
     pub fn entries_mut(&mut self) -> &mut Entries<E> {
+        self.invalidate_checkpoints();
         &mut self.entries
     }
     /// Get owned [`Entries`], Removing them from Instance
     pub fn entries_owned(&mut self) -> Entries<E> {
+        self.invalidate_checkpoints();
         let mut out = Vec::new();
         out.append(&mut self.entries);
         self.filter_entries(&mut out);
@@ -21,11 +23,7 @@ impl<T, E> ErrorLog<T, E> {
     }
     /// Filter out entries below the `max_level`
     fn filter_entries(&self, entries: &mut Entries<E>) {
-        entries.retain(|e| {
-            !matches!(e.content,
-                EntryContent::Message { level, .. } if level > self.max_level
-            )
-        });
+        entries.retain(|e| e.is_shown(&self.max_level));
     }
     /// Get cloned vector of messages. Filters out errors.
     pub fn messages_clone(&self) -> Entries<E> {
@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+use crate::{DynError, ErrorLog};
+use std::{
+    cell::{Cell, RefCell},
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+/// [`ErrorLog`] used as process-global or thread-local default log
+pub type GlobalErrorLog = ErrorLog<(), DynError>;
+
+static GLOBAL: OnceLock<Arc<Mutex<GlobalErrorLog>>> = OnceLock::new();
+
+thread_local! {
+    static LOCAL: RefCell<Option<GlobalErrorLog>> = const { RefCell::new(None) };
+    /// Whether the current thread is inside [`with_global()`]
+    static ACTIVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks the current thread as using the default log until dropped
+struct ActiveGuard;
+
+impl ActiveGuard {
+    /// Panics if the current thread is already using the default log, which would deadlock otherwise
+    fn enter() -> Self {
+        if ACTIVE.replace(true) {
+            panic!("default log used again inside `with_global()`");
+        }
+        ActiveGuard
+    }
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        ACTIVE.set(false);
+    }
+}
+
+/**
+Get the process-global log, creating an empty one on first use.
+
+Can be passed to [`install_panic_hook()`][crate::install_panic_hook] to collect panics of all threads.
+Locking it inside [`with_global()`] deadlocks.
+*/
+pub fn global_log() -> Arc<Mutex<GlobalErrorLog>> {
+    GLOBAL.get_or_init(Default::default).clone()
+}
+
+/**
+Replace the process-global log, returning the previous one
+
+# Panics
+When called inside [`with_global()`].
+*/
+pub fn set_global(log: GlobalErrorLog) -> GlobalErrorLog {
+    let _guard = ActiveGuard::enter();
+    let global = global_log();
+    let mut global = global.lock().unwrap_or_else(PoisonError::into_inner);
+    std::mem::replace(&mut *global, log)
+}
+
+/**
+Take the process-global log, leaving an empty one
+
+# Panics
+When called inside [`with_global()`].
+*/
+pub fn take_global() -> GlobalErrorLog {
+    set_global(GlobalErrorLog::default())
+}
+
+/**
+Set log of the current thread, used instead of the process-global log. Returns the previous one
+
+# Panics
+When called inside [`with_global()`].
+*/
+pub fn set_thread_local(log: GlobalErrorLog) -> Option<GlobalErrorLog> {
+    let _guard = ActiveGuard::enter();
+    LOCAL.with_borrow_mut(|local| local.replace(log))
+}
+
+/**
+Remove log of the current thread, falling back to the process-global log
+
+# Panics
+When called inside [`with_global()`].
+*/
+pub fn take_thread_local() -> Option<GlobalErrorLog> {
+    let _guard = ActiveGuard::enter();
+    LOCAL.with_borrow_mut(Option::take)
+}
+
+/**
+Run given function with the default log: the log of the current thread if set, otherwise the process-global log.
+
+Used by the `log_*!` macros when called without a log, e.g. `log_warn!("disk almost full")`.
+
+# Panics
+When the default log is used again inside the function, e.g. by calling `with_global()`, [`set_global()`] or a `log_*!` macro without a log.
+*/
+pub fn with_global<R>(fun: impl FnOnce(&mut GlobalErrorLog) -> R) -> R {
+    let _guard = ActiveGuard::enter();
+    LOCAL.with_borrow_mut(|local| match local {
+        Some(log) => fun(log),
+        None => fun(&mut global_log().lock().unwrap_or_else(PoisonError::into_inner)),
+    })
+}
@@ -0,0 +1,124 @@
+use crate::{
+    helper::{level_heading, render_section},
+    summary, Entry, EntryContent, ErrorLog, Shared,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::{Debug, Display};
+use log::LevelFilter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// How [`render_grouped()`][ErrorLog::render_grouped] groups entries
+pub enum GroupBy {
+    /// One section per level, e.g. `Errors (2):`
+    Level,
+    /// One section per error type chosen by [`error_type_fn()`][ErrorLog::error_type_fn] or [`use_error_codes()`][ErrorLog::use_error_codes], other errors are grouped as `Errors`. Messages are grouped as `Messages`.
+    ErrorType,
+    /// One section per [`origin`][crate::Entry::origin] of [`child()`][ErrorLog::child] logs, entries of the log itself are listed under `Other`
+    Origin,
+}
+
+impl<T, E> ErrorLog<T, E> {
+    /// Group entries in [`join_to_string()`][Self::join_to_string] and therefore when displaying with [`join_on_display()`][Self::join_on_display]. Default: `None`
+    pub fn group_by(&mut self, group: Option<GroupBy>) -> &mut Self {
+        self.group_by = group;
+        self
+    }
+    /// Get grouping used by [`join_to_string()`][Self::join_to_string]
+    pub fn get_group_by(&self) -> Option<GroupBy> {
+        self.group_by
+    }
+    /// Set function choosing the section of errors when grouping by [`GroupBy::ErrorType`], e.g. the name of the enum variant.
+    /// Errors it returns `None` for are grouped as `Errors`. Default: all errors are grouped as `Errors`
+    pub fn error_type_fn(
+        &mut self,
+        fun: impl Fn(&E) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.error_type_fn = Some(Shared(Arc::new(fun)));
+        self
+    }
+    /// Collapse sections of grouped output after given number of entries, ending with `and N more`. Default: no limit
+    pub fn max_per_group(&mut self, max: usize) -> &mut Self {
+        self.max_per_group = max;
+        self
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /// Section of an error when grouping by [`GroupBy::ErrorType`]
+    fn error_type(&self, err: &E) -> String {
+        let key = self.error_type_fn.as_ref().and_then(|fun| (fun.0)(err));
+        key.unwrap_or_else(|| "Errors".into())
+    }
+    /**
+    Render shown entries as summary followed by one section per group:
+    ```text
+    1 error, 2 warnings
+
+    Errors (1):
+    - failed to parse
+
+    Warnings (2):
+    - disk almost full
+    - and 1 more
+    ```
+    Sections are collapsed according to [`max_per_group()`][Self::max_per_group].
+    */
+    pub fn render_grouped(&self, group: GroupBy) -> String {
+        self.mark_displayed();
+        let shown: Vec<(&Entry<E>, String)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let msg = self.entry_message(entry)?;
+                Some((entry, msg))
+            })
+            .collect();
+        let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+        match group {
+            GroupBy::Level => {
+                for level in LevelFilter::iter() {
+                    let lines: Vec<String> = shown
+                        .iter()
+                        .filter(|(entry, _)| entry.get_level() == level)
+                        .map(|(_, msg)| msg.clone())
+                        .collect();
+                    if !lines.is_empty() {
+                        groups.push((level_heading(level).to_string(), lines));
+                    }
+                }
+            }
+            GroupBy::ErrorType | GroupBy::Origin => {
+                for (entry, msg) in &shown {
+                    let key = match (group, &entry.content) {
+                        (GroupBy::Origin, _) => entry.origin.clone().unwrap_or("Other".into()),
+                        (_, EntryContent::Error(err)) => self.error_type(err),
+                        (_, EntryContent::Message { .. }) => "Messages".into(),
+                    };
+                    let line = match group {
+                        GroupBy::Origin => format!("{}: {msg}", entry.get_level()),
+                        _ => msg.clone(),
+                    };
+                    match groups.iter_mut().find(|(heading, _)| *heading == key) {
+                        Some((_, lines)) => lines.push(line),
+                        None => groups.push((key, Vec::from([line]))),
+                    }
+                }
+            }
+        }
+        let mut out = summary(shown.iter().map(|(entry, _)| entry.get_level()));
+        for (heading, lines) in groups {
+            out.push_str("\n\n");
+            out.push_str(&render_section(
+                &format!("{heading} ({})", lines.len()),
+                &lines,
+                self.max_per_group,
+            ));
+        }
+        out
+    }
+}
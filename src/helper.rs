@@ -1,30 +1,136 @@
 use crate::if_std;
+use alloc::{format, string::String, sync::Arc, vec::Vec};
+use core::fmt::{self, Debug};
+use log::LevelFilter;
 if_std! {
-    use {
-        std::time::{SystemTime, UNIX_EPOCH},
-        time::format_description::well_known::Rfc3339
-    };
+    use std::time::{SystemTime, UNIX_EPOCH};
 }
 
 pub(crate) fn now() -> i64 {
     #[cfg(feature = "std")]
     return SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as i64;
+        .map_or(0, |d| d.as_secs() as i64);
     #[cfg(not(feature = "std"))]
     0
 }
 
-#[allow(unused_variables)]
-pub fn format_unix_timestamp(unix: i64) -> String {
-    #[cfg(not(feature = "std"))]
-    return String::from("");
-    #[cfg(feature = "std")]
-    time::OffsetDateTime::from_unix_timestamp(unix)
-        .unwrap()
-        .format(&Rfc3339)
-        .unwrap()
+/// Prefix message with formatted timestamp, if any
+pub(crate) fn with_timestamp(timestamp: &str, msg: &str) -> String {
+    match timestamp.is_empty() {
+        true => msg.into(),
+        false => format!("{timestamp}: {msg}"),
+    }
+}
+
+#[cfg(feature = "std")]
+/// Escape string for usage inside a JSON string literal
+pub(crate) fn escape_json(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape string for usage inside HTML and XML text and attributes
+pub(crate) fn escape_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Summary of the amount of entries per level, e.g. `3 errors, 12 warnings`
+pub(crate) fn summary(levels: impl Iterator<Item = LevelFilter>) -> String {
+    let mut counts = [0usize; 6];
+    for level in levels {
+        counts[level as usize] += 1;
+    }
+    let mut parts = Vec::new();
+    for (i, level) in LevelFilter::iter().enumerate() {
+        if counts[i] > 0 {
+            parts.push(format!(
+                "{} {}",
+                counts[i],
+                level_noun(level, counts[i] != 1)
+            ));
+        }
+    }
+    parts.join(", ")
+}
+
+fn level_noun(level: LevelFilter, plural: bool) -> &'static str {
+    match (level, plural) {
+        (LevelFilter::Error, false) => "error",
+        (LevelFilter::Error, true) => "errors",
+        (LevelFilter::Warn, false) => "warning",
+        (LevelFilter::Warn, true) => "warnings",
+        (LevelFilter::Info, false) => "info message",
+        (LevelFilter::Info, true) => "info messages",
+        (LevelFilter::Debug, false) => "debug message",
+        (LevelFilter::Debug, true) => "debug messages",
+        (LevelFilter::Trace, false) => "trace message",
+        (LevelFilter::Trace, true) => "trace messages",
+        (LevelFilter::Off, _) => "",
+    }
+}
+
+/// Heading of a section listing entries of the given level
+pub(crate) fn level_heading(level: LevelFilter) -> &'static str {
+    match level {
+        LevelFilter::Off => "",
+        LevelFilter::Error => "Errors",
+        LevelFilter::Warn => "Warnings",
+        LevelFilter::Info => "Info",
+        LevelFilter::Debug => "Debug",
+        LevelFilter::Trace => "Trace",
+    }
+}
+
+/// Section with given heading listing up to `max` lines, e.g. `Errors:\n- failed\n- and 2 more`
+pub(crate) fn render_section(heading: &str, lines: &[String], max: usize) -> String {
+    let mut out = format!("{heading}:");
+    for line in lines.iter().take(max) {
+        out.push_str(&format!("\n- {line}"));
+    }
+    if lines.len() > max {
+        out.push_str(&format!("\n- and {} more", lines.len() - max));
+    }
+    out
+}
+
+/// Shared value stored in [`ErrorLog`][crate::ErrorLog], like a sink or function. Not compared.
+pub(crate) struct Shared<T: ?Sized>(pub(crate) Arc<T>);
+
+impl<T: ?Sized> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized> Debug for Shared<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Shared")
+            .field(&(Arc::as_ptr(&self.0) as *const ()))
+            .finish()
+    }
 }
 
 macro_rules! instant_display_helper {
@@ -61,5 +167,4 @@ macro_rules! instant_display_helper {
         }
     };
 }
-use alloc::string::String;
 pub(crate) use instant_display_helper;
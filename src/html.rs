@@ -0,0 +1,67 @@
+use crate::{escape_html, summary, EntryContent, ErrorLog, FormatMode};
+use alloc::{format, string::String};
+use core::fmt::{Debug, Display};
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em}\
+table{border-collapse:collapse;width:100%}\
+th,td{border-bottom:1px solid #ddd;padding:.4em;text-align:left;vertical-align:top}\
+.badge{border-radius:.3em;color:#fff;font-size:.8em;font-weight:bold;padding:.1em .4em}\
+.ERROR{background:#c62828}.WARN{background:#ef6c00}.INFO{background:#2e7d32}\
+.DEBUG{background:#1565c0}.TRACE{background:#616161}\
+.message{white-space:pre-wrap}pre{background:#f5f5f5;overflow-x:auto;padding:.5em}";
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Render [`Entries`][crate::Entries] as self-contained HTML page.
+
+    Contains a table with level, timestamp and message of each entry.
+    Errors have an expandable section with their [`FormatMode::PrettyDebug`] output, which includes causes for most error types.
+    Messages lower than the [`max_level`][Self::max_level] are filtered.
+    */
+    pub fn render_html(&self) -> String {
+        self.mark_displayed();
+        let mut rows = String::new();
+        let mut levels = alloc::vec::Vec::new();
+        for entry in self.entries() {
+            let Some(msg) = entry.get_message_filter(self.get_format_mode(), &self.max_level)
+            else {
+                continue;
+            };
+            let level = entry.get_level();
+            levels.push(level);
+            let details = match entry.content {
+                EntryContent::Error(_) => format!(
+                    "<details><summary>Details</summary><pre>{}</pre></details>",
+                    escape_html(&entry.get_message(&FormatMode::PrettyDebug))
+                ),
+                EntryContent::Message { .. } => String::new(),
+            };
+            let (code, help) = self.entry_details(entry);
+            let code = match code {
+                Some(code) => format!("<code>{}</code> ", escape_html(code)),
+                None => String::new(),
+            };
+            let help = match help {
+                Some(help) => format!("<div class=\"help\">help: {}</div>", escape_html(help)),
+                None => String::new(),
+            };
+            rows.push_str(&format!(
+                "<tr><td><span class=\"badge {level}\">{level}</span></td><td>{}</td>\
+                 <td><div class=\"message\">{code}{}</div>{help}{details}</td></tr>\n",
+                escape_html(&self.format_timestamp(entry.timestamp)),
+                escape_html(&msg),
+            ));
+        }
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Error report</title>\n\
+             <style>{STYLE}</style>\n</head>\n<body>\n<h1>Error report</h1>\n<p>{}</p>\n\
+             <table>\n<tr><th>Level</th><th>Time</th><th>Message</th></tr>\n{rows}</table>\n</body>\n</html>\n",
+            escape_html(&summary(levels.into_iter())),
+        )
+    }
+    #[cfg(feature = "std")]
+    /// Write [`render_html()`][Self::render_html] output to given writer
+    pub fn write_html(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
+        writer.write_all(self.render_html().as_bytes())
+    }
+}
@@ -9,15 +9,36 @@ book: www.lesnake.xyz/opt/error_log
 
 extern crate alloc;
 
+mod checkpoint;
+mod child;
+mod ci;
 mod display;
+mod drop;
 mod entry;
+mod error_entry;
+mod escalation;
+mod exit_code;
+mod file_sink;
 mod get;
+mod global;
+mod group;
 mod helper;
+mod html;
 mod macros;
 mod manage;
+mod markdown;
 mod messages;
+mod panic;
 mod presets;
+mod prompt;
+mod response;
+mod retry;
+mod severity;
+mod sink;
+mod span;
+mod timestamp;
 mod traits;
+mod widget;
 
 macro_rules! if_std {
     ($($i:item)*) => ($(
@@ -30,20 +51,52 @@ macro_rules! if_not_std {
     )*)
 }
 
+pub use crate::checkpoint::{Checkpoint, Transaction};
+pub use crate::drop::DropPolicy;
+use crate::drop::OnDrop;
 pub use crate::entry::{Entries, EntriesExt, Entry, EntryContent};
-use crate::helper::{format_unix_timestamp, instant_display_helper, now};
+pub use crate::group::GroupBy;
+use crate::helper::{escape_html, instant_display_helper, now, summary, with_timestamp, Shared};
 pub use crate::presets::*;
-use alloc::{fmt::Debug, string::String, vec::Vec};
+use crate::sink::println_sink;
+pub use crate::sink::Sink;
+pub use crate::span::Span;
+pub use crate::timestamp::{CustomTimestampFormat, TimestampFormat};
+#[cfg(feature = "ratatui")]
+pub use crate::widget::{error_sources, ErrorLogWidget, ErrorLogWidgetState};
+#[cfg(feature = "errors")]
+pub use crate::{error_entry::ErrorLogEntry, severity::Severity};
+use alloc::{fmt::Debug, string::String, sync::Arc, vec::Vec};
 use core::fmt::Display;
+#[cfg(feature = "macros")]
+pub use error_log_macros::{error_log, ErrorLogEntry};
 pub use log::LevelFilter;
+#[allow(unused_imports)]
 pub(crate) use {if_not_std, if_std};
 if_std! {
+    pub use crate::file_sink::{FileFormat, FileSink};
+    pub use crate::global::{
+        global_log, set_global, set_thread_local, take_global, take_thread_local, with_global,
+        GlobalErrorLog,
+    };
+    pub use crate::prompt::{Choice, Prompt};
+    #[cfg(all(feature = "errors", feature = "messages"))]
+    pub use crate::retry::{Backoff, RetryPolicy};
+    #[cfg(feature = "messages")]
+    pub use crate::panic::{install_panic_hook, panic_message, panic_payload_message, SharedErrorLog};
+    use crate::helper::escape_json;
     pub use std::{println, print};
 }
 if_not_std! {
     pub use libc_print::std_name::{println, print};
 }
 
+#[doc(hidden)]
+/// Items used by macros, not part of the public API
+pub mod __private {
+    pub use alloc::format;
+}
+
 /**
 A trait Combining debug and display bounds
 */
@@ -60,8 +113,12 @@ x:`ErrorLog`, E:`Error`, T:`ok` value, U:any type
 - `x += Result<U, E>`: Shorthand for [`push_result()`][Self::push_result]
 - `x += E`: Shorthand for [`push_err`()][Self::push_err]
 - `x *= Result <T, E>`: Shorthand for [`merge_result`()][Self::merge_result]
+
+# Comparison
+Instances are compared by their entries, `ok` value and settings.
+Sinks, prompts and other functions, as well as the time of creation, are not compared.
 */
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, Debug)]
 pub struct ErrorLog<T, E> {
     format_mode: FormatMode,
     entries: Entries<E>,
@@ -72,7 +129,33 @@ pub struct ErrorLog<T, E> {
     max_level: LevelFilter,
     max_level_used: LevelFilter,
     ok: Option<T>,
-    display_fn: fn(LevelFilter, i64, String),
+    sink: Shared<dyn Sink>,
+    #[cfg(feature = "std")]
+    prompt: Option<Shared<Prompt>>,
+    #[cfg(feature = "std")]
+    #[allow(clippy::type_complexity)]
+    exit_code_fn: Option<Shared<dyn Fn(&Entries<E>) -> u8 + Send + Sync>>,
+    #[cfg(feature = "axum")]
+    #[allow(clippy::type_complexity)]
+    status_fn: Option<Shared<dyn Fn(&Entries<E>) -> axum::http::StatusCode + Send + Sync>>,
+    timestamp_format: TimestampFormat,
+    created_at: i64,
+    #[allow(clippy::type_complexity)]
+    error_level: Option<Shared<dyn Fn(&E) -> LevelFilter + Send + Sync>>,
+    #[allow(clippy::type_complexity)]
+    entry_details: Option<fn(&E) -> (Option<&'static str>, Option<&'static str>)>,
+    #[allow(clippy::type_complexity)]
+    error_type_fn: Option<Shared<dyn Fn(&E) -> Option<String> + Send + Sync>>,
+    deny_warnings: bool,
+    max_errors: Option<usize>,
+    error_count: usize,
+    escalated: bool,
+    generation: usize,
+    invalidations: Vec<(usize, usize)>,
+    on_drop: OnDrop<T, E>,
+    origin: Option<String>,
+    group_by: Option<GroupBy>,
+    max_per_group: usize,
 }
 
 impl<T, E> Default for ErrorLog<T, E> {
@@ -81,15 +164,34 @@ impl<T, E> Default for ErrorLog<T, E> {
             ok: None,
             entries: Vec::new(),
             format_mode: FormatMode::default(),
-            display_fn: |lvl, timestamp, e| {
-                println!("{lvl} {}: {e}", format_unix_timestamp(timestamp))
-            },
+            sink: Shared(Arc::new(println_sink as fn(LevelFilter, String, String))),
+            #[cfg(feature = "std")]
+            prompt: None,
+            #[cfg(feature = "std")]
+            exit_code_fn: None,
+            #[cfg(feature = "axum")]
+            status_fn: None,
             max_level: LevelFilter::Trace,
             delimiter: "".into(),
             join: false,
             max_level_used: LevelFilter::Off,
             #[cfg(feature = "instant-display")]
             instant_display: false,
+            timestamp_format: TimestampFormat::default(),
+            created_at: now(),
+            error_level: None,
+            entry_details: None,
+            error_type_fn: None,
+            deny_warnings: false,
+            max_errors: None,
+            error_count: 0,
+            escalated: false,
+            generation: 0,
+            invalidations: Vec::new(),
+            on_drop: OnDrop::default(),
+            origin: None,
+            group_by: None,
+            max_per_group: usize::MAX,
         }
     }
 }
@@ -22,12 +22,28 @@ Same as
     };
     # err_log
 # }
+```
+
+Arguments:
+1. [`Result`]
+2. [`ErrorLog`]
+3. optional: context message, pushed as error message before the error (requires `messages` feature)
 */
 macro_rules! try_add {
-    ($res: expr, $errlog: ident) => {
+    ($res: expr, $errlog: expr) => {
         match $errlog.push_result($res) {
             Some(o) => o,
-            None => return $errlog,
+            None => return ::core::mem::take(&mut $errlog),
+        }
+    };
+    ($res: expr, $errlog: expr, $($context: tt)+) => {
+        match $res {
+            Ok(o) => o,
+            Err(e) => {
+                $errlog.push_message($crate::LevelFilter::Error, $crate::__private::format!($($context)+));
+                $errlog.push_err(e);
+                return ::core::mem::take(&mut $errlog);
+            }
         }
     };
 }
@@ -57,13 +73,63 @@ Same as
 Arguments:
 1. [`Result`]
 2. [`ErrorLog`]
+3. optional: context message, pushed as error message before the error (requires `messages` feature)
 */
 macro_rules! try_merge {
-    ($res: expr, $errlog: ident) => {
+    ($res: expr, $errlog: expr) => {
         if !$errlog.merge_result($res) {
-            return $errlog;
+            return ::core::mem::take(&mut $errlog);
         }
     };
+    ($res: expr, $errlog: expr, $($context: tt)+) => {
+        match $res {
+            Ok(o) => {
+                $errlog.set_ok(o);
+            }
+            Err(e) => {
+                $errlog.push_message($crate::LevelFilter::Error, $crate::__private::format!($($context)+));
+                $errlog.push_err(e);
+                return ::core::mem::take(&mut $errlog);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "errors")]
+#[macro_export]
+/**
+Macro to [`push_result()`][crate::ErrorLog::push_result] and return given [`ErrorLog`][crate::ErrorLog] only if the escalation got triggered, see [`is_escalated()`][crate::ErrorLog::is_escalated].
+Evaluates to the `Ok` value as [`Option`].
+
+```
+# use error_log::{try_push, ErrorLog};
+fn parse_all(inputs: &[&str]) -> ErrorLog<Vec<i32>, std::num::ParseIntError> {
+    let mut err_log = ErrorLog::new();
+    err_log.max_errors(2);
+    let mut out = Vec::new();
+    for input in inputs {
+        if let Some(num) = try_push!(input.parse::<i32>(), err_log) {
+            out.push(num);
+        }
+    }
+    err_log.set_ok(out);
+    err_log
+}
+# assert_eq!(parse_all(&["1", "a", "b", "c"]).entries().len(), 2);
+```
+
+Arguments:
+1. [`Result`]
+2. [`ErrorLog`]
+*/
+macro_rules! try_push {
+    ($res: expr, $errlog: expr) => {{
+        let ok = $errlog.push_result($res);
+        if $errlog.is_escalated() {
+            return ::core::mem::take(&mut $errlog);
+        }
+        ok
+    }};
 }
 
 #[cfg(feature = "errors")]
@@ -76,9 +142,56 @@ Arguments:
 2. [`ErrorLog`]
 */
 macro_rules! return_err {
-    ($err: expr, $errlog: ident) => {
-        $errlog.push_err(e);
-        return $errlog;
+    ($err: expr, $errlog: expr) => {{
+        $errlog.push_err($err);
+        return ::core::mem::take(&mut $errlog);
+    }};
+}
+
+#[cfg(feature = "errors")]
+#[macro_export]
+/**
+Attach formatted error to given [`ErrorLog`][crate::ErrorLog] and return it. The error type has to implement `From<String>`.
+
+```
+# use error_log::{bail, ErrorLog};
+fn run(port: u16) -> ErrorLog<u16, String> {
+    let mut err_log = ErrorLog::new();
+    if port < 1024 {
+        bail!(err_log, "port {port} is reserved");
+    }
+    err_log.set_ok(port);
+    err_log
+}
+# assert_eq!(run(80).entries().len(), 1);
+```
+
+Arguments:
+1. [`ErrorLog`]
+2. format string and arguments
+*/
+macro_rules! bail {
+    ($errlog: expr, $($msg: tt)+) => {{
+        $errlog.push_err($crate::__private::format!($($msg)+));
+        return ::core::mem::take(&mut $errlog);
+    }};
+}
+
+#[cfg(feature = "errors")]
+#[macro_export]
+/**
+Attach formatted error to given [`ErrorLog`][crate::ErrorLog] and return it if the condition is false. See [`bail`]
+
+Arguments:
+1. condition
+2. [`ErrorLog`]
+3. format string and arguments
+*/
+macro_rules! ensure {
+    ($cond: expr, $errlog: expr, $($msg: tt)+) => {
+        if !$cond {
+            $crate::bail!($errlog, $($msg)+);
+        }
     };
 }
 
@@ -87,12 +200,12 @@ macro_rules! return_err {
 Set `ok` value of given [`ErrorLog`][crate::ErrorLog] and return it
 
 Arguments:
-1. err value
+1. ok value
 2. [`ErrorLog`]
 */
 macro_rules! return_ok {
-    ($err: expr, $errlog: ident) => {
-        $errlog.set_ok(e);
-        return $errlog;
-    };
+    ($ok: expr, $errlog: expr) => {{
+        $errlog.set_ok($ok);
+        return ::core::mem::take(&mut $errlog);
+    }};
 }
@@ -1,22 +1,25 @@
-use crate::{instant_display_helper, ErrorLog};
-use alloc::{string::String, vec::Vec};
+use crate::{instant_display_helper, ErrorLog, Shared, Sink};
+use alloc::{string::String, sync::Arc, vec::Vec};
 use core::fmt::{Debug, Display};
 use log::LevelFilter;
 #[cfg(feature = "errors")]
 use {
-    crate::{DebugDisplay, Entry},
+    crate::{DebugDisplay, Entry, Span},
     alloc::boxed::Box,
 };
 
 impl<T, E: Debug + Display> ErrorLog<T, E> {
     /// Appends errors from another instance
     pub fn append_entries<U>(&mut self, other: &mut ErrorLog<U, E>) -> &mut Self {
+        other.label_origin();
+        self.count_entries(&mut other.entries);
         instant_display_helper!(self, self);
         self.entries.append(&mut other.entries);
         self
     }
     /// Removes all entries from [`Self`].
     pub fn clear_entries(&mut self) -> &mut Self {
+        self.invalidate_checkpoints();
         self.entries.clear();
         self
     }
@@ -29,6 +32,9 @@ impl<T, E: Debug + Display> ErrorLog<T, E> {
     }
     /// Appends Entries before the Entries of the current `Vec`
     pub fn prepend_entries<U>(&mut self, other: &mut ErrorLog<U, E>) -> &mut Self {
+        other.label_origin();
+        self.count_entries(&mut other.entries);
+        self.invalidate_checkpoints();
         let mut entries = Vec::new();
         entries.append(&mut other.entries);
         entries.append(&mut self.entries);
@@ -56,7 +62,13 @@ impl<T, E: Debug + Display> ErrorLog<T, E> {
     }
     /// Push error to entries
     pub fn push_err(&mut self, err: impl Into<E>) -> &mut Self {
-        self.entries.push(Entry::new_error(err.into()));
+        self.push_entry(self.new_error_entry(err.into()));
+        instant_display_helper!(self);
+        self
+    }
+    /// Push error related to a location in a source text to entries
+    pub fn push_err_span(&mut self, err: impl Into<E>, span: Span) -> &mut Self {
+        self.push_entry(self.new_error_entry(err.into()).with_span(span));
         instant_display_helper!(self);
         self
     }
@@ -66,13 +78,27 @@ impl<T, E: Debug + Display> ErrorLog<T, E> {
         match res {
             Ok(o) => Some(o),
             Err(err) => {
-                self.entries.push(Entry::new_error(err.into()));
+                self.push_entry(self.new_error_entry(err.into()));
                 instant_display_helper!(self);
                 None
             }
         }
     }
 }
+#[cfg(feature = "errors")]
+impl<T, E> ErrorLog<T, E> {
+    /// Create error entry using the level set by [`use_severity()`][Self::use_severity]
+    pub(crate) fn new_error_entry(&self, err: E) -> Entry<E> {
+        match &self.error_level {
+            Some(fun) => {
+                let level = (fun.0)(&err);
+                Entry::new_error_with_level(err, level)
+            }
+            None => Entry::new_error(err),
+        }
+    }
+}
+
 impl<T, E> ErrorLog<T, E> {
     /// Create a new Instance
     pub fn new() -> Self {
@@ -84,8 +110,9 @@ impl<T, E> ErrorLog<T, E> {
     }
     /// Get owned `ok` value, discarding all entries.
     /// Related: [`display_ok()`][Self::display_ok]
-    pub fn ok_discard(self) -> Option<T> {
-        self.ok
+    pub fn ok_discard(mut self) -> Option<T> {
+        self.entries.clear();
+        self.ok.take()
     }
     /// Get mutable reference to `ok` value
     pub fn ok_mut(&mut self) -> &mut Option<T> {
@@ -95,9 +122,9 @@ impl<T, E> ErrorLog<T, E> {
     pub fn ok_take(&mut self) -> Option<T> {
         self.ok.take()
     }
-    /// Get display function
-    pub fn display_fn(&self) -> fn(LevelFilter, i64, String) {
-        self.display_fn
+    /// Get [`Sink`] used to display entries
+    pub fn get_sink(&self) -> Arc<dyn Sink> {
+        self.sink.0.clone()
     }
     /// Set `ok` value. Takes any value that can be converted to String
     pub fn set_ok(&mut self, new: impl Into<T>) -> &mut Self {
@@ -109,9 +136,15 @@ impl<T, E> ErrorLog<T, E> {
         self.ok = None;
         self
     }
-    /// Set print function
-    pub fn set_display_fn(&mut self, fun: fn(LevelFilter, i64, String)) -> &mut Self {
-        self.display_fn = fun;
+    /// Set print function.
+    ///
+    /// Arguments of the function: level, timestamp formatted using the [`TimestampFormat`][crate::TimestampFormat], message
+    pub fn set_display_fn(&mut self, fun: fn(LevelFilter, String, String)) -> &mut Self {
+        self.set_sink(fun)
+    }
+    /// Set [`Sink`] used to display entries
+    pub fn set_sink(&mut self, sink: impl Sink + 'static) -> &mut Self {
+        self.sink = Shared(Arc::new(sink));
         self
     }
 }
@@ -130,14 +163,14 @@ impl<T> ErrorLog<T, Box<dyn DebugDisplay>> {
                 true
             }
             Err(e) => {
-                self.entries.push(Entry::new_error(Box::new(e)));
+                self.push_entry(self.new_error_entry(Box::new(e)));
                 false
             }
         }
     }
     /// Puts error in a [`Box`] and stores it
     pub fn push_err_box(&mut self, err: impl DebugDisplay + 'static) -> &mut Self {
-        self.entries.push(Entry::new_error(Box::new(err)));
+        self.push_entry(self.new_error_entry(Box::new(err)));
         self
     }
     /// If the Result contains an error, put error in a [`Box`] and store it.
@@ -149,7 +182,7 @@ impl<T> ErrorLog<T, Box<dyn DebugDisplay>> {
         match res {
             Ok(o) => Some(o),
             Err(err) => {
-                self.entries.push(Entry::new_error(Box::new(err)));
+                self.push_entry(self.new_error_entry(Box::new(err)));
                 None
             }
         }
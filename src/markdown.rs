@@ -0,0 +1,87 @@
+use crate::{summary, EntryContent, ErrorLog, FormatMode};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Debug, Display};
+
+/// Escape characters with special meaning in inline Markdown
+fn escape_markdown(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Fenced code block indented for usage inside a list item.
+/// The fence is longer than any backtick sequence inside the content.
+fn code_block(content: &str) -> String {
+    let mut longest = 0;
+    let mut current = 0;
+    for c in content.chars() {
+        current = if c == '`' { current + 1 } else { 0 };
+        longest = longest.max(current);
+    }
+    let fence = "`".repeat(longest.max(2) + 1);
+    let mut out = format!("  {fence}text\n");
+    for line in content.lines() {
+        out.push_str(&format!("  {line}\n"));
+    }
+    out.push_str(&format!("  {fence}\n"));
+    out
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Render [`Entries`][crate::Entries] as Markdown, e.g. for pasting into issue trackers.
+
+    Starts with a summary of the amount of entries per level, followed by a list of the entries.
+    - Messages lower than the [`max_level`][Self::max_level] are filtered.
+    - Errors are shown using the [`FormatMode`]. Multi-line output, like [`Debug`] renderings including backtraces, is put into fenced code blocks.
+    - With [`FormatMode::Normal`], errors get a code block containing their [`FormatMode::PrettyDebug`] output.
+    */
+    pub fn render_markdown(&self) -> String {
+        self.mark_displayed();
+        let mut items = String::new();
+        let mut levels = Vec::new();
+        for entry in self.entries() {
+            let Some(msg) = entry.get_message_filter(self.get_format_mode(), &self.max_level)
+            else {
+                continue;
+            };
+            let level = entry.get_level();
+            levels.push(level);
+            let timestamp = escape_markdown(&self.format_timestamp(entry.timestamp));
+            let (code, help) = self.entry_details(entry);
+            let mut header = format!("**{level}**");
+            if let Some(code) = code {
+                header.push_str(&format!(" `{code}`"));
+            }
+            if !timestamp.is_empty() {
+                header.push_str(&format!(" {timestamp}"));
+            }
+            let is_error = matches!(entry.content, EntryContent::Error(_));
+            if msg.contains('\n') || (is_error && self.format_mode != FormatMode::Normal) {
+                items.push_str(&format!("- {header}\n"));
+                items.push_str(&code_block(&msg));
+            } else {
+                items.push_str(&format!("- {header}: {}\n", escape_markdown(&msg)));
+                if is_error {
+                    items.push_str(&code_block(&entry.get_message(&FormatMode::PrettyDebug)));
+                }
+            }
+            if let Some(help) = help {
+                items.push_str(&format!("  - help: {}\n", escape_markdown(help)));
+            }
+        }
+        let summary = summary(levels.into_iter());
+        match summary.is_empty() {
+            true => String::from("## Error report\n\nNo entries\n"),
+            false => format!("## Error report\n\n**{summary}**\n\n{items}"),
+        }
+    }
+}
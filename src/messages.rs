@@ -4,38 +4,68 @@ use alloc::string::String;
 use log::LevelFilter;
 
 #[macro_export]
-/// Attach error message to given [`ErrorLog`]
+/**
+Attach error message to given [`ErrorLog`]
+
+Without a log, e.g. `log_error!("failed")`, the message gets attached to the default log, see [`with_global()`][crate::with_global] (requires `std` feature).
+The same applies to all `log_*!` macros.
+*/
 macro_rules! log_error {
     ($errlog: ident, $($msg: tt)+) => {
-        $errlog.push_message(error_log::LevelFilter::Error, format!($($msg)+));
+        $errlog.push_message($crate::LevelFilter::Error, $crate::__private::format!($($msg)+));
+    };
+    ($($msg: tt)+) => {
+        $crate::with_global(|log| {
+            log.push_message($crate::LevelFilter::Error, $crate::__private::format!($($msg)+));
+        });
     };
 }
 #[macro_export]
 /// Attach warning to given [`ErrorLog`]
 macro_rules! log_warn {
     ($errlog: ident, $($msg: tt)+) => {
-        $errlog.push_message(error_log::LevelFilter::Warn, format!($($msg)+));
+        $errlog.push_message($crate::LevelFilter::Warn, $crate::__private::format!($($msg)+));
+    };
+    ($($msg: tt)+) => {
+        $crate::with_global(|log| {
+            log.push_message($crate::LevelFilter::Warn, $crate::__private::format!($($msg)+));
+        });
     };
 }
 #[macro_export]
 /// Attach information to given [`ErrorLog`]
 macro_rules! log_info {
     ($errlog: ident, $($msg: tt)+) => {
-        $errlog.push_message(error_log::LevelFilter::Info, format!($($msg)+));
+        $errlog.push_message($crate::LevelFilter::Info, $crate::__private::format!($($msg)+));
+    };
+    ($($msg: tt)+) => {
+        $crate::with_global(|log| {
+            log.push_message($crate::LevelFilter::Info, $crate::__private::format!($($msg)+));
+        });
     };
 }
 #[macro_export]
 /// Attaches debug message to given [`ErrorLog`]
 macro_rules! log_debug {
     ($errlog: ident, $($msg: tt)+) => {
-        $errlog.push_message(error_log::LevelFilter::Debug, format!($($msg)+));
+        $errlog.push_message($crate::LevelFilter::Debug, $crate::__private::format!($($msg)+));
+    };
+    ($($msg: tt)+) => {
+        $crate::with_global(|log| {
+            log.push_message($crate::LevelFilter::Debug, $crate::__private::format!($($msg)+));
+        });
     };
 }
 #[macro_export]
 /// Attach trace message to given [`ErrorLog`]
 macro_rules! log_trace {
     ($errlog: ident, $($msg: tt)+) => {
-        $errlog.push_message(error_log::LevelFilter::Trace, format!($($msg)+));
+        $errlog.push_message($crate::LevelFilter::Trace, $crate::__private::format!($($msg)+));
+    };
+    ($($msg: tt)+) => {
+        $crate::with_global(|log| {
+            log.push_message($crate::LevelFilter::Trace, $crate::__private::format!($($msg)+));
+        });
     };
 }
 
@@ -68,7 +98,7 @@ impl<T, E> ErrorLog<T, E> {
     - [`log_trace`]
     */
     pub fn push_message(&mut self, level: LevelFilter, msg: impl Into<String>) -> &mut Self {
-        self.entries.push(Entry::new_message(level, msg.into()));
+        self.push_entry(Entry::new_message(level, msg.into()));
         self
     }
 }
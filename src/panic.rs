@@ -0,0 +1,96 @@
+#![cfg(all(feature = "std", feature = "messages"))]
+use crate::ErrorLog;
+use log::LevelFilter;
+use std::{
+    any::Any,
+    panic::{self, PanicHookInfo, UnwindSafe},
+    sync::{Arc, Mutex, TryLockError},
+    thread,
+};
+
+/// [`ErrorLog`] that can be shared between threads, e.g. to collect panics of worker threads
+pub type SharedErrorLog<T, E> = Arc<Mutex<ErrorLog<T, E>>>;
+
+/// Get message of a panic payload, as returned by [`catch_unwind()`][std::panic::catch_unwind]
+pub fn panic_payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Box<dyn Any>".into()
+    }
+}
+
+fn thread_name() -> String {
+    thread::current().name().unwrap_or("<unnamed>").to_owned()
+}
+
+/// Format panic like the default hook: `thread 'main' panicked at src/main.rs:2:5:\nmessage`
+pub fn panic_message(info: &PanicHookInfo) -> String {
+    let location = match info.location() {
+        Some(location) => format!(" at {location}"),
+        None => String::new(),
+    };
+    format!(
+        "thread '{}' panicked{location}:\n{}",
+        thread_name(),
+        panic_payload_message(info.payload())
+    )
+}
+
+/**
+Install panic hook pushing every panic as error message to the given log.
+
+If `chain` is `true`, the previously installed hook (by default printing the panic to stderr) gets called afterwards.
+It is also called if the log is currently locked, e.g. when panicking while holding the lock, so no panic gets lost.
+*/
+pub fn install_panic_hook<T: Send + 'static, E: Send + 'static>(
+    log: SharedErrorLog<T, E>,
+    chain: bool,
+) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let recorded = match log.try_lock() {
+            Ok(mut log) => {
+                log.push_message(LevelFilter::Error, panic_message(info));
+                true
+            }
+            Err(TryLockError::Poisoned(poisoned)) => {
+                poisoned
+                    .into_inner()
+                    .push_message(LevelFilter::Error, panic_message(info));
+                true
+            }
+            Err(TryLockError::WouldBlock) => false,
+        };
+        if chain || !recorded {
+            previous(info);
+        }
+    }));
+}
+
+impl<T, E> ErrorLog<T, E> {
+    /**
+    Run given function, pushing an error message instead of propagating when it panics.
+    Returns the result of the function or `None` if it panicked.
+
+    The panic hook still runs, use [`install_panic_hook()`] to also record the location of the panic.
+    */
+    pub fn run_catching<U>(&mut self, fun: impl FnOnce() -> U + UnwindSafe) -> Option<U> {
+        match panic::catch_unwind(fun) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                self.push_message(
+                    LevelFilter::Error,
+                    format!(
+                        "thread '{}' panicked:\n{}",
+                        thread_name(),
+                        panic_payload_message(&*payload)
+                    ),
+                );
+                None
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{format_unix_timestamp, ErrorLog};
+#[cfg(any(feature = "eyre", feature = "miette"))]
+use crate::summary;
+use crate::{println_sink, with_timestamp, ErrorLog};
 #[allow(unused_imports)]
 use core::fmt::{Debug, Display};
 use log::{debug, error, info, trace, warn, LevelFilter};
@@ -26,6 +28,121 @@ impl<T: Debug> ErrorLog<T, anyhow::Error> {
     }
 }
 
+#[cfg(feature = "eyre")]
+/**
+Pre-defined [`ErrorLog`] Using [`eyre::Report`] as `E`
+
+Its suggested to use [`new_eyre()`][crate::ErrorLog::new_eyre] to load optimized settings.
+*/
+pub type ErrorLogEyre<T> = ErrorLog<T, eyre::Report>;
+
+#[cfg(feature = "eyre")]
+/// Special methods for [`ErrorLogEyre`][crate::ErrorLogEyre]
+impl<T: Debug> ErrorLog<T, eyre::Report> {
+    /// Creates a new [`ErrorLog`][crate::ErrorLog] and sets the [`FormatMode`][crate::FormatMode] to Debug, so errors get rendered by the installed [`eyre::EyreHandler`]
+    pub fn new_eyre() -> Self {
+        let mut out = Self::new();
+        out.display_mode(crate::FormatMode::Debug);
+        out
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<T> From<ErrorLog<T, eyre::Report>> for eyre::Report {
+    /// Returns the error if there is only one.
+    /// Otherwise creates a report listing all errors. Log messages are discarded.
+    fn from(value: ErrorLog<T, eyre::Report>) -> Self {
+        let mut errors = errors_owned(value);
+        if errors.len() == 1 {
+            return errors.remove(0);
+        }
+        let mut msg = errors_summary(&errors);
+        for err in &errors {
+            msg.push_str(&format!("\n- {err:#}"));
+        }
+        eyre::Report::msg(msg)
+    }
+}
+
+#[cfg(feature = "miette")]
+/**
+Pre-defined [`ErrorLog`] Using [`miette::Report`] as `E`
+
+Its suggested to use [`new_miette()`][crate::ErrorLog::new_miette] to load optimized settings.
+*/
+pub type ErrorLogMiette<T> = ErrorLog<T, miette::Report>;
+
+#[cfg(feature = "miette")]
+/// Special methods for [`ErrorLogMiette`][crate::ErrorLogMiette]
+impl<T: Debug> ErrorLog<T, miette::Report> {
+    /// Creates a new [`ErrorLog`][crate::ErrorLog] and sets the [`FormatMode`][crate::FormatMode] to Debug, so errors get rendered by the installed [`miette::ReportHandler`]
+    pub fn new_miette() -> Self {
+        let mut out = Self::new();
+        out.display_mode(crate::FormatMode::Debug);
+        out
+    }
+}
+
+#[cfg(feature = "miette")]
+/// Diagnostic containing multiple reports
+#[derive(Debug)]
+struct MietteReports(String, Vec<miette::Report>);
+
+#[cfg(feature = "miette")]
+impl Display for MietteReports {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "miette")]
+impl std::error::Error for MietteReports {}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for MietteReports {
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        Some(Box::new(
+            self.1
+                .iter()
+                .map(|report| &**report as &dyn miette::Diagnostic),
+        ))
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<T> From<ErrorLog<T, miette::Report>> for miette::Report {
+    /// Returns the error if there is only one.
+    /// Otherwise creates a report with all errors as related diagnostics. Log messages are discarded.
+    fn from(value: ErrorLog<T, miette::Report>) -> Self {
+        let mut errors = errors_owned(value);
+        if errors.len() == 1 {
+            return errors.remove(0);
+        }
+        miette::Report::new(MietteReports(errors_summary(&errors), errors))
+    }
+}
+
+#[cfg(any(feature = "eyre", feature = "miette"))]
+/// Summary of the amount of errors, e.g. `3 errors`
+fn errors_summary<E>(errors: &[E]) -> alloc::string::String {
+    match errors.is_empty() {
+        true => "no errors".into(),
+        false => summary(errors.iter().map(|_| LevelFilter::Error)),
+    }
+}
+
+#[cfg(any(feature = "eyre", feature = "miette"))]
+/// Get owned errors, discarding log messages
+fn errors_owned<T, E>(value: ErrorLog<T, E>) -> alloc::vec::Vec<E> {
+    value
+        .into_iter()
+        .filter_map(|entry| match entry.content {
+            crate::EntryContent::Error(err) => Some(err),
+            crate::EntryContent::Message { .. } => None,
+        })
+        .collect()
+}
+
 #[cfg(feature = "errors")]
 /**
 Pre-defined [`ErrorLog`] using `Box<dyn DebugDisplay>` as `E`
@@ -37,26 +154,81 @@ Unlocks additional functions:
 */
 pub type ErrorLogBox<T> = ErrorLog<T, Box<dyn DebugDisplay>>;
 
+#[cfg(feature = "std")]
+/// Boxed error that can be shared between threads and downcast
+pub type DynError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+#[cfg(all(feature = "errors", feature = "std"))]
+/**
+Pre-defined [`ErrorLog`] using [`DynError`] as `E`
+
+Any error can be added using [`push_err()`][Self::push_err], [`push_result()`][Self::push_result] or [`merge_result()`][Self::merge_result], since they convert into [`DynError`].
+
+Unlocks additional functions:
+- [`errors_of()`][Self::errors_of]
+- [`contains()`][Self::contains]
+- [`take_errors_of()`][Self::take_errors_of]
+*/
+pub type ErrorLogDyn<T> = ErrorLog<T, DynError>;
+
+#[cfg(all(feature = "errors", feature = "std"))]
+/// Special methods for [`ErrorLogDyn`][crate::ErrorLogDyn]
+impl<T> ErrorLog<T, DynError> {
+    /// Get references to all errors of type `U`
+    pub fn errors_of<U: std::error::Error + 'static>(&self) -> alloc::vec::Vec<&U> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match &entry.content {
+                crate::EntryContent::Error(err) => err.downcast_ref::<U>(),
+                crate::EntryContent::Message { .. } => None,
+            })
+            .collect()
+    }
+    /// Check whether there is an error of type `U`
+    pub fn contains<U: std::error::Error + 'static>(&self) -> bool {
+        !self.errors_of::<U>().is_empty()
+    }
+    /// Remove all errors of type `U` from entries and return them
+    pub fn take_errors_of<U: std::error::Error + 'static>(&mut self) -> alloc::vec::Vec<U> {
+        self.invalidate_checkpoints();
+        let mut out = alloc::vec::Vec::new();
+        for entry in core::mem::take(&mut self.entries) {
+            match entry.content {
+                crate::EntryContent::Error(err) if err.is::<U>() => {
+                    if let Ok(err) = err.downcast::<U>() {
+                        out.push(*err);
+                    }
+                }
+                _ => self.entries.push(entry),
+            }
+        }
+        out
+    }
+}
+
 impl<T, E> ErrorLog<T, E> {
     /// Display entries using [`log`] macros
     pub fn display_fn_log(&mut self) -> &mut Self {
-        self.display_fn = |level, unix, e| {
-            let ts = format_unix_timestamp(unix);
+        self.set_display_fn(|level, ts, e| {
+            let msg = with_timestamp(&ts, &e);
             match level {
                 LevelFilter::Off => (),
-                LevelFilter::Error => error!("{ts} {e}"),
-                LevelFilter::Warn => warn!("{ts} {e}"),
-                LevelFilter::Info => info!("{ts} {e}"),
-                LevelFilter::Debug => debug!("{ts} {e}"),
-                LevelFilter::Trace => trace!("{ts} {e}"),
+                LevelFilter::Error => error!("{msg}"),
+                LevelFilter::Warn => warn!("{msg}"),
+                LevelFilter::Info => info!("{msg}"),
+                LevelFilter::Debug => debug!("{msg}"),
+                LevelFilter::Trace => trace!("{msg}"),
             }
-        };
-        self
+        })
     }
     /// Display errors using [`println`]
     pub fn display_fn_println(&mut self) -> &mut Self {
-        self.display_fn = Self::default().display_fn;
-        self
+        self.set_display_fn(println_sink)
+    }
+    #[cfg(feature = "std")]
+    /// Append entries to the file at the given path using a [`FileSink`][crate::FileSink] with default settings
+    pub fn display_fn_file(&mut self, path: impl Into<std::path::PathBuf>) -> &mut Self {
+        self.set_sink(crate::FileSink::new(path))
     }
 }
 
@@ -64,7 +236,7 @@ impl<T, E> ErrorLog<T, E> {
 impl<T, E: Debug + Display> ErrorLog<T, E> {
     /// Display [`crate::Entries`] using [`native_dialog::MessageDialog`]
     pub fn display_fn_native_dialog(&mut self) -> &mut Self {
-        self.display_fn = |lvl, unix_ts, e| {
+        self.set_display_fn(|lvl, ts, e| {
             if let Err(dialog_err) = native_dialog::MessageDialog::new()
                 .set_type(match lvl {
                     LevelFilter::Off => return,
@@ -73,12 +245,46 @@ impl<T, E: Debug + Display> ErrorLog<T, E> {
                     _ => MessageType::Info,
                 })
                 .set_title(lvl.as_str())
-                .set_text(&format!("{}: {e}", format_unix_timestamp(unix_ts)))
+                .set_text(&with_timestamp(&ts, &e))
                 .show_alert()
             {
                 println!("Failed to show MessageDialog: {}", dialog_err)
             }
-        };
-        self
+        })
+    }
+    /**
+    Display [`crate::Entries`] in one [`native_dialog::MessageDialog`] per display call, grouped using [`render_grouped()`][Self::render_grouped].
+
+    Enables [`join_on_display()`][Self::join_on_display]. The summary is used as title.
+    */
+    pub fn display_fn_native_dialog_grouped(&mut self, group: crate::GroupBy) -> &mut Self {
+        self.join_on_display(true)
+            .group_by(Some(group))
+            .set_display_fn(|lvl, _, text| {
+                let (summary, details) = text.split_once("\n\n").unwrap_or((&text, ""));
+                if let Err(dialog_err) = native_dialog::MessageDialog::new()
+                    .set_type(match lvl {
+                        LevelFilter::Off => return,
+                        LevelFilter::Error => MessageType::Error,
+                        LevelFilter::Warn => MessageType::Warning,
+                        _ => MessageType::Info,
+                    })
+                    .set_title(summary)
+                    .set_text(details)
+                    .show_alert()
+                {
+                    println!("Failed to show MessageDialog: {}", dialog_err)
+                }
+            })
+    }
+    /**
+    Display [`crate::Entries`] in one [`native_dialog::MessageDialog`] per display call.
+
+    Entries are grouped by level and each group is truncated to `max_per_level` entries.
+    Shorthand for [`max_per_group()`][Self::max_per_group] and [`display_fn_native_dialog_grouped()`][Self::display_fn_native_dialog_grouped] using [`GroupBy::Level`][crate::GroupBy::Level].
+    */
+    pub fn display_fn_native_dialog_batched(&mut self, max_per_level: usize) -> &mut Self {
+        self.max_per_group(max_per_level)
+            .display_fn_native_dialog_grouped(crate::GroupBy::Level)
     }
 }
@@ -0,0 +1,182 @@
+#![cfg(feature = "std")]
+use crate::with_timestamp;
+use core::fmt::{self, Display};
+use log::LevelFilter;
+use std::{
+    io::{self, BufRead, BufReader, IsTerminal, Write},
+    sync::{Mutex, PoisonError},
+};
+#[cfg(feature = "errors")]
+use {
+    crate::{instant_display_helper, ErrorLog, Shared},
+    core::fmt::Debug,
+    std::sync::Arc,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Decision of the user when prompted about an error
+pub enum Choice {
+    /// Try the failed operation again
+    Retry,
+    /// Continue without the result of the failed operation
+    Ignore,
+    /// Stop the current operation
+    Abort,
+}
+
+impl Choice {
+    /// Key used to select the choice
+    fn key(&self) -> char {
+        match self {
+            Choice::Retry => 'r',
+            Choice::Ignore => 'i',
+            Choice::Abort => 'a',
+        }
+    }
+    fn name(&self) -> &'static str {
+        match self {
+            Choice::Retry => "retry",
+            Choice::Ignore => "ignore",
+            Choice::Abort => "abort",
+        }
+    }
+}
+
+impl Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]{}", self.key(), &self.name()[1..])
+    }
+}
+
+type Io = (Box<dyn BufRead + Send>, Box<dyn Write + Send>);
+
+/**
+Asks the user how to continue after an error, used by [`push_err_prompt()`][ErrorLog::push_err_prompt] and [`push_result_prompt()`][ErrorLog::push_result_prompt].
+
+By default it uses stdin and stdout. If stdin isn't a terminal, the user isn't asked and the [`fallback`][Self::fallback] choice is returned.
+*/
+pub struct Prompt {
+    choices: Vec<Choice>,
+    fallback: Choice,
+    io: Mutex<Option<Io>>,
+}
+
+impl Default for Prompt {
+    fn default() -> Self {
+        Self {
+            choices: vec![Choice::Retry, Choice::Ignore, Choice::Abort],
+            fallback: Choice::Abort,
+            io: Mutex::new(None),
+        }
+    }
+}
+
+impl Prompt {
+    /// Create prompt using stdin and stdout
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Create prompt using given reader and writer instead of stdin and stdout. Always interactive.
+    pub fn with_io(
+        reader: impl io::Read + Send + 'static,
+        writer: impl Write + Send + 'static,
+    ) -> Self {
+        Self {
+            io: Mutex::new(Some((Box::new(BufReader::new(reader)), Box::new(writer)))),
+            ..Self::default()
+        }
+    }
+    /// Set choices offered to the user. Default: Retry, Ignore, Abort
+    pub fn choices(&mut self, choices: impl Into<Vec<Choice>>) -> &mut Self {
+        self.choices = choices.into();
+        self
+    }
+    /// Set choice returned when the user can't be asked. Default: [`Choice::Abort`]
+    pub fn fallback(&mut self, choice: Choice) -> &mut Self {
+        self.fallback = choice;
+        self
+    }
+    /// Ask user how to continue after the given message
+    pub fn ask(&self, level: LevelFilter, timestamp: &str, message: &str) -> Choice {
+        let mut io = self.io.lock().unwrap_or_else(PoisonError::into_inner);
+        let res = match io.as_mut() {
+            Some((reader, writer)) => self.ask_with(reader, writer, level, timestamp, message),
+            None if io::stdin().is_terminal() => self.ask_with(
+                &mut io::stdin().lock(),
+                &mut io::stdout().lock(),
+                level,
+                timestamp,
+                message,
+            ),
+            None => return self.fallback,
+        };
+        res.unwrap_or(self.fallback)
+    }
+    fn ask_with(
+        &self,
+        reader: &mut dyn BufRead,
+        writer: &mut dyn Write,
+        level: LevelFilter,
+        timestamp: &str,
+        message: &str,
+    ) -> io::Result<Choice> {
+        if self.choices.is_empty() {
+            return Ok(self.fallback);
+        }
+        writeln!(writer, "{level} {}", with_timestamp(timestamp, message))?;
+        let options = self
+            .choices
+            .iter()
+            .map(Choice::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        loop {
+            write!(writer, "{options}? ")?;
+            writer.flush()?;
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(self.fallback);
+            }
+            let answer = line.trim().to_lowercase();
+            if let Some(choice) = self
+                .choices
+                .iter()
+                .find(|choice| answer == choice.name() || answer.chars().eq([choice.key()]))
+            {
+                return Ok(*choice);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "errors")]
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /// Set [`Prompt`] used by [`push_err_prompt()`][Self::push_err_prompt] and [`push_result_prompt()`][Self::push_result_prompt]
+    pub fn set_prompt(&mut self, prompt: Prompt) -> &mut Self {
+        self.prompt = Some(Shared(Arc::new(prompt)));
+        self
+    }
+    /// Push error to entries and ask the user how to continue.
+    /// The error counts as displayed by the prompt.
+    ///
+    /// Uses [`Prompt::default()`] if no prompt is set.
+    pub fn push_err_prompt(&mut self, err: impl Into<E>) -> Choice {
+        let entry = self.new_error_entry(err.into());
+        let level = entry.get_level();
+        let timestamp = self.format_timestamp(entry.timestamp);
+        let message = entry.get_message(&self.format_mode);
+        entry.displayed.replace(true);
+        self.push_entry(entry);
+        let choice = match &self.prompt {
+            Some(prompt) => prompt.0.ask(level, &timestamp, &message),
+            None => Prompt::default().ask(level, &timestamp, &message),
+        };
+        instant_display_helper!(self);
+        choice
+    }
+    /// Push error of result to entries and ask the user how to continue.
+    /// Returns `Ok` value of given result or the choice of the user.
+    pub fn push_result_prompt<U, F: Into<E>>(&mut self, res: Result<U, F>) -> Result<U, Choice> {
+        res.map_err(|err| self.push_err_prompt(err))
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "axum")]
+use crate::{escape_json, summary, Entries, EntryContent, ErrorLog, Shared};
+use axum::{
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+};
+use core::fmt::{Debug, Display};
+use std::sync::Arc;
+
+impl<T, E> ErrorLog<T, E> {
+    /// Set function choosing the [`StatusCode`] of the problem details response when no `ok` value is set.
+    /// Default: [`StatusCode::INTERNAL_SERVER_ERROR`]
+    pub fn status_fn(
+        &mut self,
+        fun: impl Fn(&Entries<E>) -> StatusCode + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.status_fn = Some(Shared(Arc::new(fun)));
+        self
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /// Render entries as [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details JSON document
+    pub fn to_problem_json(&self, status: StatusCode) -> String {
+        self.mark_displayed();
+        let mut errors = Vec::new();
+        let mut levels = Vec::new();
+        for entry in self.entries() {
+            if let Some(msg) = entry.get_message_filter(self.get_format_mode(), &self.max_level) {
+                levels.push(entry.get_level());
+                let kind = match entry.content {
+                    EntryContent::Error(_) => "error",
+                    EntryContent::Message { .. } => "message",
+                };
+                let (code, help) = self.entry_details(entry);
+                let mut details = String::new();
+                if let Some(code) = code {
+                    details.push_str(&format!(",\"code\":\"{}\"", escape_json(code)));
+                }
+                if let Some(help) = help {
+                    details.push_str(&format!(",\"help\":\"{}\"", escape_json(help)));
+                }
+                errors.push(format!(
+                    "{{\"kind\":\"{kind}\",\"level\":\"{}\",\"timestamp\":\"{}\",\"message\":\"{}\"{details}}}",
+                    entry.get_level(),
+                    escape_json(&self.format_timestamp(entry.timestamp)),
+                    escape_json(&msg)
+                ));
+            }
+        }
+        format!(
+            "{{\"type\":\"about:blank\",\"title\":\"{}\",\"status\":{},\"detail\":\"{}\",\"errors\":[{}]}}",
+            escape_json(status.canonical_reason().unwrap_or_default()),
+            status.as_u16(),
+            escape_json(&summary(levels.into_iter())),
+            errors.join(",")
+        )
+    }
+}
+
+impl<T: IntoResponse, E: Debug + Display> IntoResponse for ErrorLog<T, E> {
+    /// Responds with the `ok` value if set, leaving out the entries.
+    /// Otherwise responds with the entries as `application/problem+json` document, see [`to_problem_json()`][ErrorLog::to_problem_json]
+    fn into_response(mut self) -> Response {
+        if let Some(ok) = self.ok_take() {
+            self.mark_displayed();
+            return ok.into_response();
+        }
+        let status = match &self.status_fn {
+            Some(fun) => (fun.0)(self.entries()),
+            None => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (
+            status,
+            [(CONTENT_TYPE, "application/problem+json")],
+            self.to_problem_json(status),
+        )
+            .into_response()
+    }
+}
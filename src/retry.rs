@@ -0,0 +1,110 @@
+#![cfg(all(feature = "std", feature = "errors", feature = "messages"))]
+use crate::ErrorLog;
+use core::{
+    fmt::{self, Debug, Display},
+    time::Duration,
+};
+use log::LevelFilter;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Delay between the attempts of [`ErrorLog::retry()`]
+pub enum Backoff {
+    /// Retry immediately
+    None,
+    /// Wait the same time before every retry
+    Fixed(Duration),
+    /// Start with `initial` and double the delay after every attempt, up to `max`
+    Exponential {
+        /// Delay before the first retry
+        initial: Duration,
+        /// Upper limit of the delay
+        max: Duration,
+    },
+}
+
+/// How often and when [`ErrorLog::retry()`] retries an operation
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    backoff: Backoff,
+    sleeper: Arc<dyn Fn(Duration) + Send + Sync>,
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryPolicy {
+    /// Create policy running the operation up to `max_attempts` times, waiting between attempts using [`std::thread::sleep`]
+    pub fn new(max_attempts: usize, backoff: Backoff) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+            sleeper: Arc::new(std::thread::sleep),
+        }
+    }
+    /// Create policy waiting the same time before every retry
+    pub fn fixed(max_attempts: usize, delay: Duration) -> Self {
+        Self::new(max_attempts, Backoff::Fixed(delay))
+    }
+    /// Create policy doubling the delay after every attempt, starting with `initial`, up to `max`
+    pub fn exponential(max_attempts: usize, initial: Duration, max: Duration) -> Self {
+        Self::new(max_attempts, Backoff::Exponential { initial, max })
+    }
+    /// Set function used to wait between attempts, e.g. to not wait in tests. Default: [`std::thread::sleep`]
+    pub fn sleeper(&mut self, fun: impl Fn(Duration) + Send + Sync + 'static) -> &mut Self {
+        self.sleeper = Arc::new(fun);
+        self
+    }
+    /// Get maximum number of attempts
+    pub fn get_max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+    /// Get delay before the retry following the given failed attempt, starting at 1
+    pub fn delay(&self, attempt: usize) -> Duration {
+        match self.backoff {
+            Backoff::None => Duration::ZERO,
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { initial, max } => {
+                let factor = 1u32.checked_shl(attempt.saturating_sub(1) as u32);
+                factor
+                    .and_then(|factor| initial.checked_mul(factor))
+                    .map_or(max, |delay| delay.min(max))
+            }
+        }
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Run operation until it succeeds or the `max_attempts` of the policy are reached.
+
+    Every failed attempt but the last gets pushed as warning message like `attempt 1/3 failed`, followed by its error with level [`LevelFilter::Warn`].
+    The error of the last attempt gets pushed as error.
+    On success, the `ok` value gets set like [`merge_result()`][Self::merge_result]. Returns whether the operation succeeded.
+    */
+    pub fn retry<U: Into<T>, F: Into<E>>(
+        &mut self,
+        policy: &RetryPolicy,
+        mut operation: impl FnMut() -> Result<U, F>,
+    ) -> bool {
+        let max = policy.max_attempts.max(1);
+        for attempt in 1..max {
+            match operation() {
+                Ok(ok) => return self.merge_result::<U, F>(Ok(ok)),
+                Err(err) => {
+                    self.push_message(LevelFilter::Warn, format!("attempt {attempt}/{max} failed"));
+                    self.push_err_with_level(err, LevelFilter::Warn);
+                    (policy.sleeper)(policy.delay(attempt));
+                }
+            }
+        }
+        self.merge_result(operation())
+    }
+}
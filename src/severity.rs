@@ -0,0 +1,57 @@
+#![cfg(feature = "errors")]
+use crate::{instant_display_helper, Entry, ErrorLog, ErrorLogEntry, Shared};
+use alloc::sync::Arc;
+use core::fmt::{Debug, Display};
+use log::LevelFilter;
+
+/**
+Level of an error, used by [`ErrorLog`] after calling [`use_severity()`][ErrorLog::use_severity].
+
+Implemented for all types implementing [`ErrorLogEntry`].
+*/
+pub trait Severity {
+    /// Level of the error, e.g. [`LevelFilter::Warn`] for recoverable errors
+    fn severity(&self) -> LevelFilter;
+}
+
+impl<E: ErrorLogEntry> Severity for E {
+    fn severity(&self) -> LevelFilter {
+        self.level()
+    }
+}
+
+impl<T, E: Severity + 'static> ErrorLog<T, E> {
+    /// Use [`Severity::severity()`] as level of errors pushed afterwards, e.g. by [`push_err()`][Self::push_err], instead of [`LevelFilter::Error`]
+    pub fn use_severity(&mut self) -> &mut Self {
+        self.error_level = Some(Shared(Arc::new(|err: &E| err.severity())));
+        self
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Push error with given level to entries.
+
+    Errors with a level other than [`LevelFilter::Error`] get filtered by the [`max_level`][Self::max_level] like messages.
+    */
+    pub fn push_err_with_level(&mut self, err: impl Into<E>, level: LevelFilter) -> &mut Self {
+        self.push_entry(Entry::new_error_with_level(err.into(), level));
+        instant_display_helper!(self);
+        self
+    }
+    /// Push error of result with given level to entries.
+    /// Returns `Ok` value of give result as Option
+    pub fn push_result_with_level<U, F: Into<E>>(
+        &mut self,
+        res: Result<U, F>,
+        level: LevelFilter,
+    ) -> Option<U> {
+        match res {
+            Ok(o) => Some(o),
+            Err(err) => {
+                self.push_err_with_level(err, level);
+                None
+            }
+        }
+    }
+}
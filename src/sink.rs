@@ -0,0 +1,26 @@
+use crate::{println, with_timestamp};
+use alloc::string::String;
+use log::LevelFilter;
+
+/**
+Receives displayed [`Entries`][crate::Entries].
+
+Implemented for `fn(LevelFilter, String, String)`, so plain functions set via [`set_display_fn()`][crate::ErrorLog::set_display_fn] are sinks too.
+*/
+pub trait Sink: Send + Sync {
+    /// Display a single entry. Arguments: level, formatted timestamp, message
+    fn display(&self, level: LevelFilter, timestamp: String, message: String);
+    /// Called after all entries of a `display_*` call were passed to [`display()`][Self::display]
+    fn flush(&self) {}
+}
+
+impl Sink for fn(LevelFilter, String, String) {
+    fn display(&self, level: LevelFilter, timestamp: String, message: String) {
+        self(level, timestamp, message)
+    }
+}
+
+/// Default display function printing entries to stdout
+pub(crate) fn println_sink(level: LevelFilter, timestamp: String, message: String) {
+    println!("{level} {}", with_timestamp(&timestamp, &message))
+}
@@ -0,0 +1,128 @@
+use crate::ErrorLog;
+use alloc::{format, string::String, sync::Arc, vec::Vec};
+use core::{
+    fmt::{Debug, Display},
+    ops::Range,
+};
+use log::LevelFilter;
+
+/**
+Location inside a source text an [`Entry`][crate::Entry] relates to, e.g. an invalid value in a config file.
+
+When displayed, the offending line gets printed with carets under the byte range:
+```text
+ --> config.toml:2:8
+  |
+2 | port = "abc"
+  |        ^^^^^ expected a number
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    /// Name of the source, e.g. a file path
+    pub name: String,
+    /// Full source text. Shared, so many spans can point into the same text cheaply
+    pub source: Arc<str>,
+    /// Start of the byte range
+    pub start: usize,
+    /// End of the byte range (exclusive)
+    pub end: usize,
+    /// Label printed next to the carets. Can be empty
+    pub label: String,
+}
+
+impl Span {
+    /// Create new span
+    pub fn new(
+        name: impl Into<String>,
+        source: impl Into<Arc<str>>,
+        range: Range<usize>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            source: source.into(),
+            start: range.start,
+            end: range.end,
+            label: label.into(),
+        }
+    }
+    /// Get byte range, clamped to the source and to char boundaries
+    pub fn range(&self) -> Range<usize> {
+        let floor = |mut i: usize| {
+            i = i.min(self.source.len());
+            while !self.source.is_char_boundary(i) {
+                i -= 1;
+            }
+            i
+        };
+        let start = floor(self.start);
+        start..floor(self.end).max(start)
+    }
+    /// Byte offset of the start of the line containing the start of the span
+    fn line_start(&self) -> usize {
+        self.source[..self.range().start]
+            .rfind('\n')
+            .map_or(0, |i| i + 1)
+    }
+    /// Get 1-based line and column of the start of the span
+    pub fn line_column(&self) -> (usize, usize) {
+        let before = &self.source[..self.range().start];
+        (
+            before.matches('\n').count() + 1,
+            before[self.line_start()..].chars().count() + 1,
+        )
+    }
+    /// Render location, offending source line and carets
+    pub fn render(&self) -> String {
+        let range = self.range();
+        let (line, column) = self.line_column();
+        let line_start = self.line_start();
+        let line_end = self.source[range.start..]
+            .find('\n')
+            .map_or(self.source.len(), |i| range.start + i);
+        let text = self.source[line_start..line_end].trim_end_matches('\r');
+        let carets = self.source[range.start..range.end.min(line_end)]
+            .chars()
+            .count()
+            .max(1);
+        let number = format!("{line}");
+        let pad = " ".repeat(number.len());
+        let mut out = format!(
+            "{pad}--> {}:{line}:{column}\n{pad} |\n{number} | {text}\n{pad} | {}{}",
+            self.name,
+            " ".repeat(column - 1),
+            "^".repeat(carets)
+        );
+        if !self.label.is_empty() {
+            out.push(' ');
+            out.push_str(&self.label);
+        }
+        out
+    }
+}
+
+impl<T, E: Debug + Display> ErrorLog<T, E> {
+    /**
+    Render [`Entries`][crate::Entries] as compiler-like diagnostics, showing the source snippets of entries with a [`Span`].
+    Messages lower than the [`max_level`][Self::max_level] are filtered.
+    */
+    pub fn render_diagnostics(&self) -> String {
+        self.mark_displayed();
+        let mut out = Vec::new();
+        for entry in self.entries() {
+            if let Some(msg) = self.entry_message(entry) {
+                let severity = match entry.get_level() {
+                    LevelFilter::Off => "",
+                    LevelFilter::Error => "error",
+                    LevelFilter::Warn => "warning",
+                    LevelFilter::Info => "info",
+                    LevelFilter::Debug => "debug",
+                    LevelFilter::Trace => "trace",
+                };
+                out.push(format!("{severity}: {msg}\n"));
+            }
+        }
+        out.join("\n")
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+};
+#[cfg(feature = "std")]
+use {
+    alloc::format,
+    time::{
+        format_description::{
+            self,
+            well_known::{Rfc2822, Rfc3339},
+            OwnedFormatItem,
+        },
+        OffsetDateTime, UtcOffset,
+    },
+};
+
+/**
+How timestamps of [`Entries`][crate::Entries] get formatted when displayed.
+
+Formatting never panics: if a timestamp is out of range or a custom format description is invalid, the raw unix timestamp is used instead.
+
+Note: Without the `std` feature no timestamps are available, so every variant formats to an empty String.
+*/
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimestampFormat {
+    /// [RFC3339](https://www.rfc-editor.org/rfc/rfc3339) in UTC, e.g. `2023-04-01T12:00:00Z` (Default)
+    #[default]
+    Rfc3339,
+    /// [RFC2822](https://www.rfc-editor.org/rfc/rfc2822) in UTC, e.g. `Sat, 01 Apr 2023 12:00:00 +0000`
+    Rfc2822,
+    /// RFC3339 using the local UTC offset, e.g. `2023-04-01T14:00:00+02:00`
+    ///
+    /// Falls back to UTC if the local offset can't be determined.
+    Local,
+    /// Custom format in UTC, created using [`custom()`][Self::custom]
+    Custom(CustomTimestampFormat),
+    /// Time elapsed since the [`ErrorLog`][crate::ErrorLog] was created, e.g. `+00:01:05`
+    Elapsed,
+    /// Don't display timestamps
+    None,
+}
+
+impl TimestampFormat {
+    /**
+    Custom [`time` format description](https://time-rs.github.io/book/api/format-description.html) (version 2) in UTC, e.g. `[hour]:[minute]:[second]`.
+
+    The description gets parsed once here, not on every formatted timestamp.
+    */
+    pub fn custom(description: impl Into<String>) -> Self {
+        Self::Custom(CustomTimestampFormat::new(description.into()))
+    }
+    /**
+    Format unix timestamp.
+
+    `created` is the unix timestamp used as origin for [`Elapsed`][Self::Elapsed].
+    */
+    #[allow(unused_variables)]
+    pub fn format(&self, unix: i64, created: i64) -> String {
+        #[cfg(not(feature = "std"))]
+        return String::new();
+        #[cfg(feature = "std")]
+        match self {
+            Self::None => String::new(),
+            Self::Elapsed => {
+                let secs = unix.saturating_sub(created);
+                let sign = if secs < 0 { '-' } else { '+' };
+                let secs = secs.unsigned_abs();
+                format!(
+                    "{sign}{:02}:{:02}:{:02}",
+                    secs / 3600,
+                    secs / 60 % 60,
+                    secs % 60
+                )
+            }
+            _ => self.format_date(unix).unwrap_or_else(|| format!("{unix}")),
+        }
+    }
+    #[cfg(feature = "std")]
+    /// Format timestamp as date, returning [`None`] on failure
+    fn format_date(&self, unix: i64) -> Option<String> {
+        let date = OffsetDateTime::from_unix_timestamp(unix).ok()?;
+        match self {
+            Self::Rfc3339 => date.format(&Rfc3339).ok(),
+            Self::Rfc2822 => date.format(&Rfc2822).ok(),
+            Self::Local => {
+                let offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+                date.to_offset(offset).format(&Rfc3339).ok()
+            }
+            Self::Custom(custom) => date.format(custom.items.as_ref()?).ok(),
+            Self::Elapsed | Self::None => None,
+        }
+    }
+}
+
+/**
+Parsed custom format description of [`TimestampFormat::Custom`], created using [`TimestampFormat::custom()`].
+
+Compared by the description it was created from.
+*/
+#[derive(Clone)]
+pub struct CustomTimestampFormat {
+    description: String,
+    /// [`None`] if the description is invalid
+    #[cfg(feature = "std")]
+    items: Option<OwnedFormatItem>,
+}
+
+impl CustomTimestampFormat {
+    fn new(description: String) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            items: format_description::parse_owned::<2>(&description).ok(),
+            description,
+        }
+    }
+    /// Get the format description this was created from
+    pub fn as_str(&self) -> &str {
+        &self.description
+    }
+}
+
+impl Debug for CustomTimestampFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomTimestampFormat")
+            .field(&self.description)
+            .finish()
+    }
+}
+
+impl PartialEq for CustomTimestampFormat {
+    fn eq(&self, other: &Self) -> bool {
+        self.description == other.description
+    }
+}
+
+impl Eq for CustomTimestampFormat {}
+
+impl PartialOrd for CustomTimestampFormat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CustomTimestampFormat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.description.cmp(&other.description)
+    }
+}
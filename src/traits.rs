@@ -1,20 +1,99 @@
-use crate::{if_std, Entry, ErrorLog};
-use alloc::vec::IntoIter;
+use crate::{if_std, Entries, Entry, ErrorLog, FormatMode, GroupBy, TimestampFormat};
+use alloc::{string::String, vec::IntoIter};
+use core::cmp::Ordering;
 #[cfg(feature = "helper-traits")]
 use core::{
     fmt::{Debug, Display},
     ops::{AddAssign, Deref, DerefMut, MulAssign},
 };
+use log::LevelFilter;
 if_std! {
     use std::process::Termination;
 }
 
+/// Fields compared by [`PartialEq`] and [`Ord`]
+#[allow(clippy::type_complexity)]
+type Compared<'a, T, E> = (
+    (
+        &'a Entries<E>,
+        &'a Option<T>,
+        &'a FormatMode,
+        bool,
+        &'a String,
+        bool,
+        LevelFilter,
+        LevelFilter,
+    ),
+    (
+        &'a TimestampFormat,
+        bool,
+        Option<usize>,
+        usize,
+        bool,
+        &'a Option<String>,
+        Option<GroupBy>,
+        usize,
+    ),
+);
+
+impl<T, E> ErrorLog<T, E> {
+    /// Get fields used for comparison, leaving out shared functions and the time of creation
+    fn compared(&self) -> Compared<'_, T, E> {
+        #[cfg(feature = "instant-display")]
+        let instant_display = self.instant_display;
+        #[cfg(not(feature = "instant-display"))]
+        let instant_display = false;
+        (
+            (
+                &self.entries,
+                &self.ok,
+                &self.format_mode,
+                instant_display,
+                &self.delimiter,
+                self.join,
+                self.max_level,
+                self.max_level_used,
+            ),
+            (
+                &self.timestamp_format,
+                self.deny_warnings,
+                self.max_errors,
+                self.error_count,
+                self.escalated,
+                &self.origin,
+                self.group_by,
+                self.max_per_group,
+            ),
+        )
+    }
+}
+
+impl<T: PartialEq, E: PartialEq> PartialEq for ErrorLog<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.compared() == other.compared()
+    }
+}
+
+impl<T: Eq, E: Eq> Eq for ErrorLog<T, E> {}
+
+impl<T: PartialOrd, E: PartialOrd> PartialOrd for ErrorLog<T, E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compared().partial_cmp(&other.compared())
+    }
+}
+
+impl<T: Ord, E: Ord> Ord for ErrorLog<T, E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compared().cmp(&other.compared())
+    }
+}
+
 impl<T, E> IntoIterator for ErrorLog<T, E> {
     type Item = Entry<E>;
     type IntoIter = IntoIter<Self::Item>;
     /// Iterates over Error stored.
-    fn into_iter(self) -> Self::IntoIter {
-        self.entries.into_iter()
+    fn into_iter(mut self) -> Self::IntoIter {
+        core::mem::take(&mut self.entries).into_iter()
     }
 }
 
@@ -39,13 +118,11 @@ impl<T, U, E: Debug + Display> AddAssign<Result<U, E>> for ErrorLog<T, E> {
 }
 
 #[cfg(feature = "std")]
-impl<T, E> Termination for ErrorLog<T, E> {
+impl<T, E: core::fmt::Debug + core::fmt::Display> Termination for ErrorLog<T, E> {
+    /// Displays [`Entries`][crate::Entries] that weren't displayed yet and reports the [`exit_code()`][ErrorLog::exit_code]
     fn report(self) -> std::process::ExitCode {
-        use std::process::ExitCode;
-        match self.ok.is_some() {
-            true => ExitCode::SUCCESS,
-            false => ExitCode::FAILURE,
-        }
+        self.display_undisplayed();
+        self.exit_code().into()
     }
 }
 
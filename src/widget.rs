@@ -0,0 +1,175 @@
+#![cfg(feature = "ratatui")]
+use crate::{Entry, EntryContent, ErrorLog, FormatMode};
+use core::fmt::{Debug, Display};
+use log::LevelFilter;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+/// Selection and scroll position of an [`ErrorLogWidget`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorLogWidgetState {
+    list: ListState,
+}
+
+impl ErrorLogWidgetState {
+    /// Get index of the selected entry, counting only entries shown by the widget
+    pub fn selected(&self) -> Option<usize> {
+        self.list.selected()
+    }
+    /// Select entry at given index, counting only entries shown by the widget
+    pub fn select(&mut self, index: Option<usize>) {
+        self.list.select(index);
+    }
+    /// Select next entry or the first one if no entry is selected
+    pub fn select_next(&mut self) {
+        self.list.select_next();
+    }
+    /// Select previous entry or the last one if no entry is selected
+    pub fn select_previous(&mut self) {
+        self.list.select_previous();
+    }
+}
+
+/**
+[`StatefulWidget`] rendering the [`Entries`][crate::Entries] of an [`ErrorLog`] as scrollable list colored by level,
+with a detail pane showing the selected entry.
+*/
+pub struct ErrorLogWidget<'a, T, E> {
+    log: &'a ErrorLog<T, E>,
+    filter: LevelFilter,
+    causes: Option<fn(&E) -> Vec<String>>,
+    block: Option<Block<'a>>,
+}
+
+impl<'a, T, E> ErrorLogWidget<'a, T, E> {
+    /// Create widget showing the entries of the given [`ErrorLog`], filtered by its [`max_level`][ErrorLog::max_level]
+    pub fn new(log: &'a ErrorLog<T, E>) -> Self {
+        Self {
+            log,
+            filter: log.max_level,
+            causes: None,
+            block: None,
+        }
+    }
+    /// Only show entries with given level or higher
+    pub fn filter(mut self, level: LevelFilter) -> Self {
+        self.filter = level;
+        self
+    }
+    /// Set function returning the causes of an error, shown in the detail pane. E.g. [`error_sources`]
+    pub fn causes(mut self, fun: fn(&E) -> Vec<String>) -> Self {
+        self.causes = Some(fun);
+        self
+    }
+    /// Surround the list with given [`Block`]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+/// Get the messages of the [`source()`][std::error::Error::source] chain of an error
+pub fn error_sources<E: std::error::Error>(err: &E) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut source = err.source();
+    while let Some(err) = source {
+        out.push(err.to_string());
+        source = err.source();
+    }
+    out
+}
+
+fn level_style(level: LevelFilter) -> Style {
+    Style::default().fg(match level {
+        LevelFilter::Off => Color::Reset,
+        LevelFilter::Error => Color::Red,
+        LevelFilter::Warn => Color::Yellow,
+        LevelFilter::Info => Color::Green,
+        LevelFilter::Debug => Color::Blue,
+        LevelFilter::Trace => Color::DarkGray,
+    })
+}
+
+impl<'a, T, E: Debug + Display> ErrorLogWidget<'a, T, E> {
+    fn detail(&self, entry: &Entry<E>) -> Vec<Line<'static>> {
+        let level = entry.get_level();
+        let mut lines = vec![
+            Line::from(Span::styled(level.as_str(), level_style(level))),
+            Line::from(self.log.format_timestamp(entry.timestamp)),
+            Line::default(),
+        ];
+        lines.extend(
+            entry
+                .get_message(self.log.get_format_mode())
+                .lines()
+                .map(|line| Line::from(line.to_owned())),
+        );
+        if let (EntryContent::Error(err), Some(causes)) = (&entry.content, self.causes) {
+            let causes = causes(err);
+            if !causes.is_empty() {
+                lines.push(Line::default());
+                lines.push(Line::from("Caused by:"));
+                for (i, cause) in causes.iter().enumerate() {
+                    lines.push(Line::from(format!("{i:>4}: {cause}")));
+                }
+            }
+        }
+        if matches!(entry.content, EntryContent::Error(_))
+            && self.log.get_format_mode() != &FormatMode::PrettyDebug
+        {
+            lines.push(Line::default());
+            lines.extend(
+                entry
+                    .get_message(&FormatMode::PrettyDebug)
+                    .lines()
+                    .map(|line| Line::from(line.to_owned())),
+            );
+        }
+        lines
+    }
+}
+
+impl<'a, T, E: Debug + Display> StatefulWidget for ErrorLogWidget<'a, T, E> {
+    type State = ErrorLogWidgetState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let entries: Vec<&Entry<E>> = self
+            .log
+            .entries()
+            .iter()
+            .filter(|e| e.get_level() <= self.filter)
+            .collect();
+        for entry in &entries {
+            entry.displayed.replace(true);
+        }
+        let items = entries.iter().map(|entry| {
+            let level = entry.get_level();
+            let msg = entry.get_message(self.log.get_format_mode());
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<5} ", level.as_str()), level_style(level)),
+                Span::raw(msg.lines().next().unwrap_or_default().to_owned()),
+            ]))
+        });
+        let [list_area, detail_area] =
+            Layout::vertical([Constraint::Percentage(60), Constraint::Fill(1)]).areas(area);
+        let mut list = List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        if let Some(block) = self.block.clone() {
+            list = list.block(block);
+        }
+        StatefulWidget::render(list, list_area, buf, &mut state.list);
+        let detail = match state.selected().and_then(|i| entries.get(i)) {
+            Some(entry) => self.detail(entry),
+            None => Vec::new(),
+        };
+        Paragraph::new(detail)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title("Details"))
+            .render(detail_area, buf);
+    }
+}
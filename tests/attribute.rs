@@ -0,0 +1,72 @@
+#![cfg(all(feature = "macros", feature = "messages"))]
+use error_log::{error_log, log_info, ErrorLog};
+use pretty_assertions::assert_eq;
+use std::num::ParseIntError;
+
+#[error_log]
+fn double(input: &str) -> ErrorLog<i32, ParseIntError> {
+    let num = input.parse::<i32>()?;
+    log_info!(log, "parsed {num}");
+    if num == 0 {
+        return 1;
+    }
+    num * 2
+}
+
+#[error_log]
+fn sum(inputs: &[&str]) -> ErrorLog<i32, ParseIntError> {
+    let parse =
+        |input: &str| -> Result<i32, ParseIntError> { Ok(input.trim().parse::<i32>()?.abs()) };
+    let mut out = 0;
+    for input in inputs {
+        out += parse(input)?;
+    }
+    out
+}
+
+#[test]
+fn wrap_body() {
+    let ok = double("21");
+    assert_eq!(*ok.ok(), Some(42));
+    assert_eq!(ok.entries().len(), 1);
+    assert_eq!(*double("0").ok(), Some(1));
+    let err = double("x");
+    assert_eq!(*err.ok(), None);
+    assert_eq!(err.entries().len(), 1);
+    assert_eq!(*sum(&["1", "2"]).ok(), Some(3));
+    assert_eq!(sum(&["1", "y", "z"]).entries().len(), 1);
+}
+
+#[error_log]
+fn show(input: &str) -> ErrorLog<String, ParseIntError> {
+    // not comma separated, left untouched
+    let padding = vec![' '; 2];
+    format!(
+        "{}got {}",
+        String::from_iter(padding),
+        input.parse::<i32>()?
+    )
+}
+
+#[test]
+fn macro_arguments() {
+    assert_eq!(*show("1").ok(), Some("  got 1".into()));
+    let err = show("x");
+    assert_eq!(*err.ok(), None);
+    assert_eq!(err.entries().len(), 1);
+}
+
+#[cfg(feature = "anyhow")]
+#[error_log]
+fn preset(input: &str) -> error_log::ErrorLogAnyhow<i32> {
+    input.parse::<i32>()?
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn preset_settings() {
+    let err_log = preset("x");
+    assert_eq!(err_log.get_format_mode(), &error_log::FormatMode::Debug);
+    assert_eq!(err_log.entries().len(), 1);
+    assert_eq!(*preset("2").ok(), Some(2));
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "axum")]
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use error_log::{DropPolicy, ErrorLog, LevelFilter, TimestampFormat};
+use pretty_assertions::assert_eq;
+use std::num::ParseIntError;
+use tower::ServiceExt;
+
+async fn parse(input: &'static str) -> ErrorLog<String, ParseIntError> {
+    let mut err_log = ErrorLog::new();
+    err_log
+        .timestamp_format(TimestampFormat::None)
+        .status_fn(|_| StatusCode::UNPROCESSABLE_ENTITY);
+    if let Some(num) = err_log.push_result(input.parse::<i32>()) {
+        err_log.set_ok(format!("{num}"));
+    }
+    err_log
+}
+
+async fn request(app: Router) -> (StatusCode, Option<String>, String) {
+    let res = app
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let content_type = res
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_owned());
+    let status = res.status();
+    let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+    (
+        status,
+        content_type,
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn ok() {
+    let (status, _, body) = request(Router::new().route("/", get(|| parse("42")))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "42");
+}
+
+#[tokio::test]
+async fn problem_details() {
+    let (status, content_type, body) =
+        request(Router::new().route("/", get(|| parse("abc")))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(content_type.as_deref(), Some("application/problem+json"));
+    assert_eq!(
+        body,
+        "{\"type\":\"about:blank\",\"title\":\"Unprocessable Entity\",\"status\":422,\
+         \"detail\":\"1 error\",\"errors\":[{\"kind\":\"error\",\"level\":\"ERROR\",\
+         \"timestamp\":\"\",\"message\":\"invalid digit found in string\"}]}"
+    );
+}
+
+#[tokio::test]
+async fn drop_policy() {
+    let handler = || async {
+        let mut err_log = parse("abc").await;
+        err_log.drop_policy(DropPolicy::DebugAssertPanic);
+        err_log
+    };
+    let (status, _, _) = request(Router::new().route("/", get(handler))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let handler = || async {
+        let mut err_log = parse("1").await;
+        err_log
+            .drop_policy(DropPolicy::DebugAssertPanic)
+            .push_message(LevelFilter::Warn, "deprecated endpoint");
+        err_log
+    };
+    let (status, _, body) = request(Router::new().route("/", get(handler))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "1");
+}
@@ -0,0 +1,37 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{log_warn, ErrorLog, GroupBy, LevelFilter, TimestampFormat};
+use pretty_assertions::assert_eq;
+use std::sync::Mutex;
+
+static SHOWN: Mutex<Vec<(LevelFilter, String)>> = Mutex::new(Vec::new());
+
+fn collect(level: LevelFilter, _: String, text: String) {
+    SHOWN.lock().unwrap().push((level, text));
+}
+
+#[test]
+fn batched() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log
+        .timestamp_format(TimestampFormat::None)
+        .join_on_display(true)
+        .group_by(Some(GroupBy::Level))
+        .max_per_group(2)
+        .set_display_fn(collect);
+    for i in 0..3 {
+        log_warn!(err_log, "warning {i}");
+    }
+    err_log.push_err("failed");
+    err_log.display_ref();
+    // Nothing to show
+    err_log.clear_entries().display_ref();
+    assert_eq!(
+        *SHOWN.lock().unwrap(),
+        vec![(
+            LevelFilter::Error,
+            String::from(
+                "1 error, 3 warnings\n\nErrors (1):\n- failed\n\nWarnings (3):\n- warning 0\n- warning 1\n- and 1 more"
+            )
+        )]
+    );
+}
@@ -0,0 +1,99 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{ErrorLog, LevelFilter};
+use pretty_assertions::assert_eq;
+
+mod common;
+use common::messages;
+
+#[test]
+fn rollback() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.push_err("before");
+    let checkpoint = err_log.checkpoint();
+    err_log.push_err("step 1");
+    err_log.push_message(LevelFilter::Warn, "step 2");
+    assert_eq!(err_log.entries_since(checkpoint).len(), 2);
+    assert_eq!(err_log.error_count(), 2);
+    err_log.rollback_to(checkpoint);
+    assert_eq!(messages(&err_log), vec!["before".to_string()]);
+    assert_eq!(err_log.error_count(), 1);
+    assert!(err_log.entries_since(checkpoint).is_empty());
+    err_log.push_err("retry");
+    err_log.rollback_to(checkpoint);
+    assert_eq!(messages(&err_log), vec!["before".to_string()]);
+}
+
+#[test]
+fn rollback_escalation() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.set_ok(()).deny_warnings(true).max_errors(2);
+    err_log.push_err("before");
+    let checkpoint = err_log.checkpoint();
+    err_log.push_message(LevelFilter::Warn, "denied");
+    assert!(err_log.is_escalated());
+    err_log.rollback_to(checkpoint);
+    assert!(!err_log.is_escalated());
+    assert_eq!(err_log.error_count(), 1);
+    err_log.max_errors(1);
+    assert!(err_log.is_escalated());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn rollback_exit_code() {
+    use std::process::{ExitCode, Termination};
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.set_ok(()).deny_warnings(true);
+    {
+        let mut transaction = err_log.transaction();
+        transaction.push_message(LevelFilter::Warn, "denied");
+        assert!(transaction.is_escalated());
+    }
+    assert!(!err_log.is_escalated());
+    assert_eq!(err_log.report(), ExitCode::SUCCESS);
+}
+
+#[test]
+#[should_panic(expected = "stale checkpoint")]
+fn stale_checkpoint() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.push_err("first");
+    let checkpoint = err_log.checkpoint();
+    err_log.clear_entries();
+    err_log.push_err("second");
+    err_log.rollback_to(checkpoint);
+}
+
+#[test]
+#[should_panic(expected = "stale checkpoint")]
+fn stale_after_rollback() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    let start = err_log.checkpoint();
+    err_log.push_err("first");
+    let later = err_log.checkpoint();
+    err_log.rollback_to(start);
+    err_log.push_err("second");
+    err_log.push_err("third");
+    err_log.entries_since(later);
+}
+
+#[test]
+fn transaction() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.push_err("before");
+    {
+        let mut transaction = err_log.transaction();
+        transaction.push_err("discarded");
+        assert_eq!(transaction.entries_since_start().len(), 1);
+    }
+    let mut transaction = err_log.transaction();
+    transaction.push_err("rolled back");
+    transaction.rollback();
+    let mut transaction = err_log.transaction();
+    transaction.push_err("kept");
+    transaction.commit();
+    assert_eq!(
+        messages(&err_log),
+        vec!["before".to_string(), "kept".to_string()]
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{ErrorLog, LevelFilter, Span};
+use pretty_assertions::assert_eq;
+
+fn app() -> ErrorLog<(), String> {
+    let mut root = ErrorLog::<(), String>::new();
+    root.max_level(LevelFilter::Warn);
+    root.push_err("config missing");
+    let mut plugins = root.child::<()>("plugins");
+    assert_eq!(plugins.get_origin(), Some("plugins"));
+    let mut markdown = plugins.child::<u8>("markdown");
+    markdown.push_err("failed to parse front matter");
+    markdown.push_message(LevelFilter::Info, "filtered");
+    plugins.push_message(LevelFilter::Warn, "2 plugins disabled");
+    plugins.append_entries(&mut markdown);
+    let mut spell = plugins.child::<()>("spell");
+    spell.push_err("dictionary not found");
+    plugins.prepend_entries(&mut spell);
+    root.append_entries(&mut plugins);
+    root
+}
+
+#[test]
+fn origins() {
+    let root = app();
+    let origins: Vec<Option<&str>> = root.entries().iter().map(|e| e.origin.as_deref()).collect();
+    assert_eq!(
+        origins,
+        vec![
+            None,
+            Some("plugins/spell"),
+            Some("plugins"),
+            Some("plugins/markdown"),
+            Some("plugins/markdown")
+        ]
+    );
+    assert_eq!(root.entries_from("plugins").count(), 4);
+    assert_eq!(root.entries_from("plugins/markdown").count(), 2);
+    assert_eq!(root.entries_from("plug").count(), 0);
+    assert_eq!(root.group_by_origin().len(), 4);
+}
+
+#[test]
+fn render() {
+    let mut root = app();
+    assert_eq!(
+        root.render_by_origin(),
+        "3 errors, 1 warning\n\n\
+        Other (1):\n- ERROR: config missing\n\n\
+        plugins/spell (1):\n- ERROR: dictionary not found\n\n\
+        plugins (1):\n- WARN: 2 plugins disabled\n\n\
+        plugins/markdown (1):\n- ERROR: failed to parse front matter"
+    );
+    let span = Span::new("a.toml", "key = ", 6..6, "value expected");
+    let mut config = root.child::<()>("config");
+    config.push_err_span("invalid value", span.clone());
+    root.append_entries(&mut config);
+    assert!(root.render_by_origin().ends_with(&format!(
+        "config (1):\n- ERROR: invalid value\n{}",
+        span.render()
+    )));
+}
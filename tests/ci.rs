@@ -0,0 +1,48 @@
+#![cfg(feature = "messages")]
+#![cfg(feature = "errors")]
+use error_log::{log_info, log_warn, ErrorLog};
+use pretty_assertions::assert_eq;
+
+fn err_log() -> ErrorLog<i32, String> {
+    let mut err_log = ErrorLog::new();
+    err_log.push_err("check <a> failed\ndetails");
+    log_warn!(err_log, "check #2 is slow");
+    log_info!(err_log, "check 3");
+    err_log
+}
+
+#[test]
+fn junit() {
+    assert_eq!(
+        err_log().render_junit("lint"),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n\
+         <testsuite name=\"lint\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\">\n\
+         <testcase classname=\"lint\" name=\"check &lt;a&gt; failed\">\n\
+         <failure message=\"check &lt;a&gt; failed\ndetails\">&quot;check &lt;a&gt; failed\\ndetails&quot;</failure>\n\
+         </testcase>\n\
+         <testcase classname=\"lint\" name=\"check #2 is slow\">\n\
+         <skipped message=\"check #2 is slow\"/>\n</testcase>\n\
+         <testcase classname=\"lint\" name=\"check 3\"/>\n\
+         </testsuite>\n</testsuites>\n"
+    );
+}
+
+#[test]
+fn tap() {
+    assert_eq!(
+        err_log().render_tap(),
+        "TAP version 13\n1..3\n\
+         not ok 1 - check <a> failed\n  ---\n  message: |\n    \"check <a> failed\\ndetails\"\n  ...\n\
+         ok 2 - check \\#2 is slow # SKIP warning\n\
+         ok 3 - check 3\n"
+    );
+}
+
+#[test]
+fn junit_invalid_chars() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    log_warn!(err_log, "bell\u{7} next\u{85}line\u{FFFF}");
+    assert!(err_log
+        .render_junit("lint")
+        .contains("<skipped message=\"bell next\u{85}line\"/>"));
+}
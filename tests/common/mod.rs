@@ -0,0 +1,40 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+use error_log::{ErrorLog, LevelFilter, Sink};
+use std::{
+    fmt::{Debug, Display},
+    sync::{Arc, Mutex},
+};
+
+/// [`Sink`] collecting displayed entries as `LEVEL message`
+#[derive(Default, Clone)]
+pub struct Collect(Arc<Mutex<Vec<String>>>);
+
+impl Sink for Collect {
+    fn display(&self, level: LevelFilter, _: String, message: String) {
+        self.0.lock().unwrap().push(format!("{level} {message}"));
+    }
+}
+
+impl Collect {
+    /// Take entries collected so far
+    pub fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+/// Messages of all entries
+pub fn messages<T, E: Debug + Display>(log: &ErrorLog<T, E>) -> Vec<String> {
+    log.entries()
+        .iter()
+        .map(|entry| entry.get_message(log.get_format_mode()))
+        .collect()
+}
+
+/// Level and message of all entries
+pub fn leveled_messages<T, E: Debug + Display>(log: &ErrorLog<T, E>) -> Vec<(LevelFilter, String)> {
+    log.entries()
+        .iter()
+        .map(|entry| (entry.get_level(), entry.get_message(log.get_format_mode())))
+        .collect()
+}
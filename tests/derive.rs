@@ -0,0 +1,100 @@
+#![cfg(feature = "macros")]
+use error_log::{ErrorLog, ErrorLogEntry, LevelFilter};
+use pretty_assertions::assert_eq;
+use std::fmt;
+
+#[derive(Debug, ErrorLogEntry)]
+#[entry(help = "see the manual")]
+enum ConfigError {
+    #[entry(code = "E1001", help = "run `app init` to create one")]
+    NotFound,
+    #[entry(code = "W1002", level = "warn")]
+    UnknownKey(String),
+    #[entry(level = "Info")]
+    Deprecated { key: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NotFound => write!(f, "config file not found"),
+            ConfigError::UnknownKey(key) => write!(f, "unknown key {key}"),
+            ConfigError::Deprecated { key } => write!(f, "{key} is deprecated"),
+        }
+    }
+}
+
+#[derive(Debug, ErrorLogEntry)]
+#[entry(code = "E2000", level = "debug")]
+struct Plain;
+
+#[test]
+fn attributes() {
+    assert_eq!(ConfigError::NotFound.code(), Some("E1001"));
+    assert_eq!(
+        ConfigError::NotFound.help(),
+        Some("run `app init` to create one")
+    );
+    assert_eq!(ConfigError::NotFound.level(), LevelFilter::Error);
+    let unknown = ConfigError::UnknownKey("x".into());
+    assert_eq!(unknown.code(), Some("W1002"));
+    assert_eq!(unknown.help(), Some("see the manual"));
+    assert_eq!(unknown.level(), LevelFilter::Warn);
+    let deprecated = ConfigError::Deprecated { key: "y".into() };
+    assert_eq!(deprecated.code(), None);
+    assert_eq!(deprecated.level(), LevelFilter::Info);
+    assert_eq!(Plain.code(), Some("E2000"));
+    assert_eq!(Plain.level(), LevelFilter::Debug);
+}
+
+#[test]
+fn push_err_uses_level() {
+    let mut err_log = ErrorLog::<(), ConfigError>::new();
+    err_log.push_err(ConfigError::UnknownKey("a".into()));
+    err_log.use_severity();
+    err_log.push_err(ConfigError::NotFound);
+    err_log.push_err(ConfigError::UnknownKey("b".into()));
+    err_log.push_result::<(), _>(Err(ConfigError::Deprecated { key: "c".into() }));
+    let levels: Vec<LevelFilter> = err_log.entries().iter().map(|e| e.get_level()).collect();
+    assert_eq!(
+        levels,
+        vec![
+            LevelFilter::Error,
+            LevelFilter::Error,
+            LevelFilter::Warn,
+            LevelFilter::Info
+        ]
+    );
+}
+
+#[cfg(feature = "messages")]
+#[test]
+fn details() {
+    let mut err_log = ErrorLog::<(), ConfigError>::new();
+    err_log.push_err(ConfigError::NotFound);
+    assert_eq!(err_log.join_to_string().unwrap(), "config file not found");
+    err_log
+        .use_entry_details()
+        .timestamp_format(error_log::TimestampFormat::None);
+    err_log.push_err(ConfigError::Deprecated { key: "y".into() });
+    assert_eq!(
+        err_log.join_to_string().unwrap(),
+        "[E1001] config file not found\nhelp: run `app init` to create one\
+         y is deprecated\nhelp: see the manual"
+    );
+    assert!(err_log.render_markdown().contains(
+        "- **ERROR** `E1001`: config file not found\n  ```text\n  NotFound\n  ```\n  \
+         - help: run \\`app init\\` to create one\n"
+    ));
+    assert!(err_log.render_html().contains(
+        "<div class=\"message\"><code>E1001</code> config file not found</div>\
+         <div class=\"help\">help: run `app init` to create one</div>"
+    ));
+    #[cfg(feature = "axum")]
+    assert!(err_log
+        .to_problem_json(axum::http::StatusCode::BAD_REQUEST)
+        .contains(
+            "\"message\":\"config file not found\",\"code\":\"E1001\",\
+             \"help\":\"run `app init` to create one\"}"
+        ));
+}
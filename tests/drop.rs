@@ -0,0 +1,88 @@
+#![cfg(all(feature = "std", feature = "errors"))]
+use error_log::{DropPolicy, ErrorLog};
+use pretty_assertions::assert_eq;
+
+mod common;
+use common::Collect;
+
+fn new_log(sink: &Collect, policy: DropPolicy) -> ErrorLog<(), String> {
+    let mut err_log = ErrorLog::new();
+    err_log.set_sink(sink.clone()).drop_policy(policy);
+    err_log
+}
+
+#[test]
+fn display_undisplayed() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::DisplayUndisplayed);
+    err_log.push_err("first");
+    err_log.display_ref();
+    assert_eq!(sink.take(), vec!["ERROR first".to_string()]);
+    err_log.push_err("second");
+    assert_eq!(err_log.undisplayed_count(), 1);
+    drop(err_log);
+    assert_eq!(sink.take(), vec!["ERROR second".to_string()]);
+}
+
+#[test]
+fn ignore() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::Ignore);
+    err_log.push_err("lost");
+    drop(err_log);
+    assert_eq!(sink.take(), Vec::<String>::new());
+}
+
+#[test]
+fn displayed_entries_are_fine() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::DebugAssertPanic);
+    err_log.push_err("shown");
+    assert_eq!(err_log.display_ok(), None);
+    let mut discarded = new_log(&sink, DropPolicy::DebugAssertPanic);
+    discarded.push_err("discarded");
+    assert_eq!(discarded.ok_discard(), None);
+}
+
+#[test]
+fn rendered_entries_are_fine() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::DebugAssertPanic);
+    err_log.push_err("rendered");
+    assert!(err_log.render_markdown().contains("rendered"));
+    drop(err_log);
+    assert_eq!(sink.take(), Vec::<String>::new());
+}
+
+#[test]
+fn clones_ignore() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::DisplayUndisplayed);
+    err_log.push_err("once");
+    let clone = err_log.clone();
+    assert_eq!(clone.get_drop_policy(), DropPolicy::Ignore);
+    assert_eq!(clone, err_log);
+    drop(clone);
+    drop(err_log);
+    assert_eq!(sink.take(), vec!["ERROR once".to_string()]);
+}
+
+#[test]
+fn displayed_entries_compare_equal() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::Ignore);
+    err_log.push_err("shown");
+    let clone = err_log.clone();
+    err_log.display_ref();
+    assert_eq!(err_log, clone);
+    assert_eq!(err_log.entries()[0], clone.entries()[0]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "ErrorLog dropped with 1 undisplayed entries")]
+fn debug_assert_panic() {
+    let sink = Collect::default();
+    let mut err_log = new_log(&sink, DropPolicy::DebugAssertPanic);
+    err_log.push_err("forgotten");
+}
@@ -0,0 +1,41 @@
+#![cfg(all(feature = "errors", feature = "messages", feature = "std"))]
+use error_log::ErrorLogDyn;
+use pretty_assertions::assert_eq;
+use std::{fmt, num::ParseIntError};
+
+#[derive(Debug, PartialEq)]
+struct Custom(u8);
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "custom {}", self.0)
+    }
+}
+
+impl std::error::Error for Custom {}
+
+#[test]
+fn downcast() {
+    let mut err_log = ErrorLogDyn::<i32>::new();
+    err_log.push_result("abc".parse::<i32>());
+    err_log.push_err(Custom(1));
+    err_log.push_message(log::LevelFilter::Info, "note");
+    err_log.push_err(Custom(2));
+    err_log.merge_result("42".parse::<i32>());
+    assert!(err_log.contains::<Custom>());
+    assert!(err_log.contains::<ParseIntError>());
+    assert!(!err_log.contains::<fmt::Error>());
+    assert_eq!(err_log.errors_of::<Custom>(), vec![&Custom(1), &Custom(2)]);
+    assert_eq!(
+        err_log.take_errors_of::<Custom>(),
+        vec![Custom(1), Custom(2)]
+    );
+    assert!(!err_log.contains::<Custom>());
+    assert_eq!(err_log.entries().len(), 2);
+}
+
+#[test]
+fn send() {
+    fn assert_send<S: Send>(_: S) {}
+    assert_send(ErrorLogDyn::<i32>::new());
+}
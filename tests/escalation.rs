@@ -0,0 +1,87 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{log_warn, try_push, ErrorLog, LevelFilter};
+use pretty_assertions::assert_eq;
+use std::num::ParseIntError;
+
+fn parse_all(inputs: &[&str], max: usize) -> ErrorLog<Vec<i32>, ParseIntError> {
+    let mut err_log = ErrorLog::new();
+    err_log.max_errors(max);
+    let mut out = Vec::new();
+    for input in inputs {
+        if let Some(num) = try_push!(input.parse::<i32>(), err_log) {
+            out.push(num);
+        }
+    }
+    err_log.set_ok(out);
+    err_log
+}
+
+#[test]
+fn threshold() {
+    let all = parse_all(&["1", "a", "2", "b"], 3);
+    assert!(!all.is_escalated());
+    assert_eq!(*all.ok(), Some(vec![1, 2]));
+    assert_eq!(all.error_count(), 2);
+    let aborted = parse_all(&["1", "a", "2", "b", "3", "c"], 2);
+    assert!(aborted.is_escalated());
+    assert_eq!(*aborted.ok(), None);
+    assert_eq!(aborted.entries().len(), 2);
+}
+
+#[test]
+fn deny_warnings() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    log_warn!(err_log, "allowed");
+    assert!(!err_log.is_escalated());
+    err_log.deny_warnings(true);
+    err_log.push_message(LevelFilter::Info, "info");
+    assert!(!err_log.is_escalated());
+    log_warn!(err_log, "denied");
+    err_log.push_err_with_level("recoverable", LevelFilter::Warn);
+    assert!(err_log.is_escalated());
+    let levels: Vec<LevelFilter> = err_log.entries().iter().map(|e| e.get_level()).collect();
+    assert_eq!(
+        levels,
+        vec![
+            LevelFilter::Warn,
+            LevelFilter::Info,
+            LevelFilter::Error,
+            LevelFilter::Error
+        ]
+    );
+    assert_eq!(err_log.error_count(), 2);
+}
+
+#[test]
+fn merged() {
+    let mut parent = ErrorLog::<(), String>::new();
+    parent.max_errors(2);
+    let mut child = parent.child::<()>("child");
+    child.push_err("failed");
+    parent.append_entries(&mut child);
+    assert_eq!(parent.error_count(), 1);
+    assert!(!parent.is_escalated());
+    let mut other = ErrorLog::<(), String>::new();
+    log_warn!(other, "denied");
+    parent.deny_warnings(true).prepend_entries(&mut other);
+    assert_eq!(parent.entries()[0].get_level(), LevelFilter::Error);
+    assert_eq!(parent.error_count(), 2);
+    assert!(parent.is_escalated());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn termination() {
+    use std::process::{ExitCode, Termination};
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.set_ok(());
+    let mut escalated = err_log.clone();
+    assert_eq!(err_log.report(), ExitCode::SUCCESS);
+    escalated.max_errors(1).push_err("fatal");
+    assert_eq!(escalated.report(), ExitCode::FAILURE);
+    let mut parent = ErrorLog::<(), String>::new();
+    let mut child = ErrorLog::<(), String>::new();
+    child.push_err("fatal");
+    parent.max_errors(1).append_entries(&mut child);
+    assert_eq!(parent.report(), ExitCode::FAILURE);
+}
@@ -0,0 +1,53 @@
+#![cfg(all(feature = "std", feature = "messages", feature = "errors"))]
+use error_log::{ErrorLog, LevelFilter};
+use pretty_assertions::assert_eq;
+use std::process::{ExitCode, Termination};
+
+mod common;
+use common::Collect;
+
+#[test]
+fn default_policy() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    assert_eq!(err_log.exit_code(), 1);
+    err_log.set_ok(());
+    err_log.push_err("error");
+    assert_eq!(err_log.exit_code(), 0);
+    err_log.max_errors(1);
+    assert_eq!(err_log.exit_code(), 1);
+}
+
+#[test]
+fn by_level() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.exit_code_by_level(1, 2);
+    assert_eq!(err_log.exit_code(), 0);
+    err_log.push_message(LevelFilter::Info, "info");
+    assert_eq!(err_log.exit_code(), 0);
+    err_log.push_message(LevelFilter::Warn, "warn");
+    assert_eq!(err_log.exit_code(), 2);
+    err_log.push_err("error");
+    assert_eq!(err_log.exit_code(), 1);
+}
+
+#[test]
+fn custom() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.exit_code_fn(|entries| match entries.len() {
+        0 => 0,
+        _ => 42,
+    });
+    assert_eq!(err_log.exit_code(), 0);
+    err_log.push_err("error");
+    assert_eq!(err_log.exit_code(), 42);
+}
+
+#[test]
+fn report_displays_entries() {
+    let sink = Collect::default();
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.set_sink(sink.clone()).exit_code_by_level(3, 4);
+    err_log.push_message(LevelFilter::Warn, "careful");
+    assert_eq!(err_log.report(), ExitCode::from(4));
+    assert_eq!(sink.take(), vec!["WARN careful".to_string()]);
+}
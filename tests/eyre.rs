@@ -0,0 +1,26 @@
+#![cfg(feature = "eyre")]
+
+use error_log::ErrorLogEyre;
+use eyre::WrapErr;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn eyre() {
+    let mut err_log = ErrorLogEyre::<String>::new_eyre();
+    err_log += "abc".parse::<i32>().wrap_err("Error");
+    assert_eq!(err_log.entries().len(), 1);
+    let report = eyre::Report::from(err_log);
+    assert_eq!(report.to_string(), "Error");
+}
+
+#[test]
+fn multiple() {
+    let mut err_log = ErrorLogEyre::<String>::new_eyre();
+    err_log += "abc".parse::<i32>().wrap_err("First");
+    err_log += "def".parse::<i32>().wrap_err("Second");
+    let report = eyre::Report::from(err_log);
+    assert_eq!(
+        report.to_string(),
+        "2 errors\n- First: invalid digit found in string\n- Second: invalid digit found in string"
+    );
+}
@@ -0,0 +1,99 @@
+#![cfg(all(feature = "std", feature = "errors"))]
+use error_log::{ErrorLog, FileFormat, FileSink, LevelFilter, Sink, TimestampFormat};
+use pretty_assertions::assert_eq;
+use std::{fs, path::PathBuf};
+
+mod common;
+use common::Collect;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("error_log_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn append() {
+    let path = temp_dir("append").join("errors.log");
+    for _ in 0..2 {
+        let mut err_log = ErrorLog::<i32, String>::new();
+        err_log
+            .timestamp_format(TimestampFormat::None)
+            .display_fn_file(&path);
+        err_log.push_err("failed");
+        err_log.display_ok();
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "ERROR failed\nERROR failed\n"
+    );
+}
+
+#[test]
+fn json_lines() {
+    let path = temp_dir("json").join("errors.jsonl");
+    let mut sink = FileSink::new(&path);
+    sink.format(FileFormat::JsonLines);
+    sink.display(LevelFilter::Warn, "ts".into(), "say \"hi\"\n".into());
+    drop(sink);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "{\"level\":\"WARN\",\"timestamp\":\"ts\",\"message\":\"say \\\"hi\\\"\\n\"}\n"
+    );
+}
+
+#[test]
+fn rotation() {
+    let dir = temp_dir("rotation");
+    let path = dir.join("errors.log");
+    let mut sink = FileSink::new(&path);
+    // Every line is 14 bytes long
+    sink.max_size(30).keep(2);
+    for i in 0..7 {
+        sink.display(LevelFilter::Error, String::new(), format!("entry {i}"));
+    }
+    sink.flush();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "ERROR entry 6\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("errors.log.1")).unwrap(),
+        "ERROR entry 4\nERROR entry 5\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("errors.log.2")).unwrap(),
+        "ERROR entry 2\nERROR entry 3\n"
+    );
+    assert!(!dir.join("errors.log.3").exists());
+}
+
+#[test]
+fn rotate_existing() {
+    let dir = temp_dir("rotate_existing");
+    let path = dir.join("errors.log");
+    fs::write(&path, "ERROR entry 0\nERROR entry 1\n").unwrap();
+    let mut sink = FileSink::new(&path);
+    sink.max_size(20).keep(1);
+    sink.display(LevelFilter::Error, String::new(), "entry 2".into());
+    sink.flush();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "ERROR entry 2\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("errors.log.1")).unwrap(),
+        "ERROR entry 0\nERROR entry 1\n"
+    );
+}
+
+#[test]
+fn fallback() {
+    let path = temp_dir("fallback").join("missing").join("errors.log");
+    let collect = Collect::default();
+    let mut sink = FileSink::new(path);
+    sink.fallback(collect.clone());
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.set_sink(sink);
+    err_log.push_err("failed");
+    err_log.display_ok();
+    let messages = collect.take();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with("ERROR Failed to write to"));
+    assert_eq!(messages[1], "ERROR failed");
+}
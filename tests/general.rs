@@ -7,3 +7,14 @@ struct Foo;
 fn no_traits() {
     ErrorLog::<i32, Foo>::new();
 }
+
+#[test]
+/// Sinks and the time of creation are not compared
+fn compare() {
+    assert_eq!(ErrorLog::<i32, String>::new(), ErrorLog::new());
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.set_display_fn(|_, _, _| {});
+    assert_eq!(err_log, ErrorLog::new());
+    err_log.set_ok(1);
+    assert!(err_log > ErrorLog::new());
+}
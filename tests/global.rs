@@ -0,0 +1,48 @@
+#![cfg(all(feature = "messages", feature = "std"))]
+use error_log::{
+    log_info, log_warn, set_thread_local, take_global, take_thread_local, with_global,
+    GlobalErrorLog, LevelFilter,
+};
+use pretty_assertions::assert_eq;
+use std::{panic, thread};
+
+mod common;
+use common::leveled_messages;
+
+#[test]
+fn global_and_thread_local() {
+    log_warn!("disk {}% full", 90);
+    thread::spawn(|| log_info!("from worker")).join().unwrap();
+    set_thread_local(GlobalErrorLog::new());
+    log_info!("only local");
+    let local = take_thread_local().unwrap();
+    assert_eq!(
+        leveled_messages(&local),
+        vec![(LevelFilter::Info, "only local".to_string())]
+    );
+    let mut explicit = GlobalErrorLog::new();
+    log_warn!(explicit, "explicit {}", 1);
+    assert_eq!(explicit.entries().len(), 1);
+    assert_eq!(with_global(|log| log.entries().len()), 2);
+    let global = take_global();
+    assert_eq!(
+        leveled_messages(&global),
+        vec![
+            (LevelFilter::Warn, "disk 90% full".to_string()),
+            (LevelFilter::Info, "from worker".to_string())
+        ]
+    );
+    assert!(with_global(|log| log.entries().is_empty()));
+}
+
+#[test]
+fn reentrant() {
+    thread::spawn(|| {
+        let res = panic::catch_unwind(|| with_global(|_| take_global()));
+        assert!(res.is_err());
+        // Usable again afterwards
+        with_global(|_| ());
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,108 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{ErrorLog, ErrorLogEntry, GroupBy, LevelFilter};
+use pretty_assertions::assert_eq;
+use std::fmt;
+
+#[derive(Debug)]
+enum AppError {
+    Io(String),
+    Parse { line: usize },
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Io(path) => write!(f, "can't read {path}"),
+            AppError::Parse { line } => write!(f, "syntax error in line {line}"),
+        }
+    }
+}
+
+impl ErrorLogEntry for AppError {
+    fn code(&self) -> Option<&'static str> {
+        match self {
+            AppError::Io(_) => None,
+            AppError::Parse { .. } => Some("E0001"),
+        }
+    }
+}
+
+fn app() -> ErrorLog<(), AppError> {
+    let mut err_log = ErrorLog::new();
+    err_log.max_level(LevelFilter::Warn);
+    err_log.push_err(AppError::Io("a.txt".into()));
+    err_log.push_message(LevelFilter::Warn, "disk almost full");
+    let mut plugin = err_log.child::<()>("plugin");
+    plugin.push_err(AppError::Parse { line: 3 });
+    plugin.push_err(AppError::Io("b.txt".into()));
+    plugin.push_message(LevelFilter::Info, "filtered");
+    err_log.append_entries(&mut plugin);
+    err_log
+}
+
+#[test]
+fn by_level() {
+    let mut err_log = app();
+    err_log.max_per_group(2);
+    assert_eq!(
+        err_log.render_grouped(GroupBy::Level),
+        "3 errors, 1 warning\n\n\
+        Errors (3):\n- can't read a.txt\n- syntax error in line 3\n- and 1 more\n\n\
+        Warnings (1):\n- disk almost full"
+    );
+}
+
+#[test]
+fn by_type_and_origin() {
+    let mut err_log = app();
+    assert_eq!(
+        err_log.render_grouped(GroupBy::ErrorType),
+        "3 errors, 1 warning\n\n\
+        Errors (3):\n- can't read a.txt\n- syntax error in line 3\n- can't read b.txt\n\n\
+        Messages (1):\n- disk almost full"
+    );
+    err_log.use_error_codes();
+    assert_eq!(
+        err_log.render_grouped(GroupBy::ErrorType),
+        "3 errors, 1 warning\n\n\
+        Errors (2):\n- can't read a.txt\n- can't read b.txt\n\n\
+        Messages (1):\n- disk almost full\n\n\
+        E0001 (1):\n- syntax error in line 3"
+    );
+    err_log.error_type_fn(|err| {
+        Some(match err {
+            AppError::Io(_) => "Io".into(),
+            AppError::Parse { .. } => "Parse".into(),
+        })
+    });
+    assert_eq!(
+        err_log.render_grouped(GroupBy::ErrorType),
+        "3 errors, 1 warning\n\n\
+        Io (2):\n- can't read a.txt\n- can't read b.txt\n\n\
+        Messages (1):\n- disk almost full\n\n\
+        Parse (1):\n- syntax error in line 3"
+    );
+    assert_eq!(
+        err_log.render_grouped(GroupBy::Origin),
+        "3 errors, 1 warning\n\n\
+        Other (2):\n- ERROR: can't read a.txt\n- WARN: disk almost full\n\n\
+        plugin (2):\n- ERROR: syntax error in line 3\n- ERROR: can't read b.txt"
+    );
+}
+
+#[test]
+fn join_to_string() {
+    let mut err_log = app();
+    assert!(err_log
+        .join_to_string()
+        .unwrap()
+        .starts_with("can't read a.txt"));
+    err_log.group_by(Some(GroupBy::Level));
+    assert_eq!(err_log.get_group_by(), Some(GroupBy::Level));
+    assert!(err_log
+        .join_to_string()
+        .unwrap()
+        .starts_with("3 errors, 1 warning\n\nErrors (3):"));
+    err_log.clear_entries();
+    assert_eq!(err_log.join_to_string(), None);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "messages")]
+use error_log::{log_info, log_trace, ErrorLog, LevelFilter};
+
+#[test]
+fn render_html() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.max_level(LevelFilter::Info);
+    err_log.push_message(LevelFilter::Error, "<script>alert('x')</script>");
+    log_info!(err_log, "a & b");
+    log_trace!(err_log, "filtered");
+    let html = err_log.render_html();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<p>1 error, 1 info message</p>"));
+    assert!(html.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+    assert!(html.contains("<span class=\"badge INFO\">INFO</span>"));
+    assert!(html.contains("a &amp; b"));
+    assert!(!html.contains("filtered"));
+    assert!(!html.contains("<details>"));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "errors"))]
+fn write_html() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.push_err("failed");
+    let mut out = Vec::new();
+    err_log.write_html(&mut out).unwrap();
+    let html = String::from_utf8(out).unwrap();
+    assert_eq!(html, err_log.render_html());
+    assert!(
+        html.contains("<details><summary>Details</summary><pre>&quot;failed&quot;</pre></details>")
+    );
+}
@@ -3,6 +3,8 @@
 use std::num::ParseIntError;
 
 use error_log::{try_add, try_merge, ErrorLog};
+
+mod common;
 #[test]
 fn main() {
     let out = run();
@@ -23,3 +25,94 @@ pub fn run_merge() -> ErrorLog<i32, ParseIntError> {
     try_merge!("abc".parse::<i32>(), err_log);
     err_log
 }
+
+#[cfg(feature = "messages")]
+mod context {
+    use super::*;
+    use common::messages;
+    use error_log::{bail, ensure, return_err, return_ok};
+    use pretty_assertions::assert_eq;
+
+    struct Ctx {
+        errors: ErrorLog<i32, String>,
+    }
+
+    fn parse_ctx(input: &str) -> ErrorLog<i32, String> {
+        let mut ctx = Ctx {
+            errors: ErrorLog::new(),
+        };
+        let num = try_add!(
+            input.parse::<i32>().map_err(|e| e.to_string()),
+            ctx.errors,
+            "failed to parse {input:?}"
+        );
+        ensure!(num >= 0, ctx.errors, "{num} is negative");
+        if num == 0 {
+            return_err!("zero".to_string(), ctx.errors);
+        }
+        if num > 100 {
+            bail!(ctx.errors, "{num} is too large");
+        }
+        return_ok!(num, ctx.errors);
+    }
+
+    impl Ctx {
+        fn parse(&mut self, input: &str) -> ErrorLog<i32, String> {
+            let num = try_add!(
+                input.parse::<i32>().map_err(|e| e.to_string()),
+                self.errors,
+                "failed to parse {input:?}"
+            );
+            ensure!(num >= 0, self.errors, "{num} is negative");
+            return_ok!(num, self.errors);
+        }
+    }
+
+    #[test]
+    fn mut_self() {
+        let mut ctx = Ctx {
+            errors: ErrorLog::new(),
+        };
+        assert_eq!(*ctx.parse("3").ok(), Some(3));
+        assert_eq!(
+            messages(&ctx.parse("-3")),
+            vec!["-3 is negative".to_string()]
+        );
+        assert_eq!(ctx.parse("z").entries().len(), 2);
+        // The returned log got taken out of the context
+        assert!(ctx.errors.entries().is_empty());
+    }
+
+    #[test]
+    fn place_expressions() {
+        assert_eq!(*parse_ctx("42").ok(), Some(42));
+        assert_eq!(
+            messages(&parse_ctx("x")),
+            vec![
+                "failed to parse \"x\"".to_string(),
+                "invalid digit found in string".to_string()
+            ]
+        );
+        assert_eq!(
+            messages(&parse_ctx("-1")),
+            vec!["-1 is negative".to_string()]
+        );
+        assert_eq!(messages(&parse_ctx("0")), vec!["zero".to_string()]);
+        assert_eq!(
+            messages(&parse_ctx("101")),
+            vec!["101 is too large".to_string()]
+        );
+    }
+
+    fn merge_ctx(input: &str) -> ErrorLog<i32, ParseIntError> {
+        let mut errors = ErrorLog::new();
+        try_merge!(input.parse::<i32>(), errors, "reading {input}");
+        errors
+    }
+
+    #[test]
+    fn merge_context() {
+        assert_eq!(*merge_ctx("7").ok(), Some(7));
+        assert_eq!(merge_ctx("y").entries().len(), 2);
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "messages")]
+#![cfg(feature = "errors")]
+use error_log::{log_debug, log_warn, ErrorLog, FormatMode, LevelFilter, TimestampFormat};
+use pretty_assertions::assert_eq;
+
+fn err_log() -> ErrorLog<i32, String> {
+    let mut err_log = ErrorLog::new();
+    err_log
+        .timestamp_format(TimestampFormat::None)
+        .max_level(LevelFilter::Info);
+    err_log.push_err("failed to parse `a_b`");
+    log_warn!(err_log, "first\nsecond");
+    log_debug!(err_log, "filtered");
+    err_log
+}
+
+#[test]
+fn normal() {
+    assert_eq!(
+        err_log().render_markdown(),
+        "## Error report\n\n**1 error, 1 warning**\n\n\
+         - **ERROR**: failed to parse \\`a\\_b\\`\n  ```text\n  \"failed to parse `a_b`\"\n  ```\n\
+         - **WARN**\n  ```text\n  first\n  second\n  ```\n"
+    );
+}
+
+#[test]
+fn debug() {
+    let mut err_log = err_log();
+    err_log.display_mode(FormatMode::Debug);
+    err_log.clear_entries().push_err("```");
+    assert_eq!(
+        err_log.render_markdown(),
+        "## Error report\n\n**1 error**\n\n- **ERROR**\n  ````text\n  \"```\"\n  ````\n"
+    );
+}
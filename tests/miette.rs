@@ -0,0 +1,30 @@
+#![cfg(feature = "miette")]
+
+use error_log::ErrorLogMiette;
+use miette::{IntoDiagnostic, WrapErr};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn miette() {
+    let mut err_log = ErrorLogMiette::<String>::new_miette();
+    err_log += "abc".parse::<i32>().into_diagnostic().wrap_err("Error");
+    assert_eq!(err_log.entries().len(), 1);
+    assert!(err_log.join_to_string().unwrap().contains("Error"));
+    let report = miette::Report::from(err_log);
+    assert_eq!(report.to_string(), "Error");
+}
+
+#[test]
+fn multiple() {
+    let mut err_log = ErrorLogMiette::<String>::new_miette();
+    err_log += "abc".parse::<i32>().into_diagnostic().wrap_err("First");
+    err_log += "def".parse::<i32>().into_diagnostic().wrap_err("Second");
+    let report = miette::Report::from(err_log);
+    assert_eq!(report.to_string(), "2 errors");
+    let related: Vec<String> = report
+        .related()
+        .unwrap()
+        .map(|diagnostic| diagnostic.to_string())
+        .collect();
+    assert_eq!(related, ["First", "Second"]);
+}
@@ -10,3 +10,20 @@ fn native_dialog() {
     err_log += "abc123".parse::<i32>();
     assert_eq!(err_log.display_ok(), None);
 }
+
+#[test]
+fn native_dialog_batched() {
+    let mut err_log = ErrorLog::<i32, ParseIntError>::new();
+    err_log.display_fn_native_dialog_batched(10);
+    err_log += "abc123".parse::<i32>();
+    err_log += "abc".parse::<i32>();
+    assert_eq!(err_log.display_ok(), None);
+}
+
+#[test]
+fn native_dialog_grouped() {
+    let mut err_log = ErrorLog::<i32, ParseIntError>::new();
+    err_log.display_fn_native_dialog_grouped(error_log::GroupBy::Level);
+    err_log += "abc123".parse::<i32>();
+    assert_eq!(err_log.display_ok(), None);
+}
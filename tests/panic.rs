@@ -0,0 +1,51 @@
+#![cfg(all(feature = "messages", feature = "std"))]
+use error_log::{install_panic_hook, ErrorLog, LevelFilter, SharedErrorLog};
+use pretty_assertions::assert_eq;
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[test]
+fn run_catching() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    assert_eq!(err_log.run_catching(|| 5), Some(5));
+    assert_eq!(
+        err_log.run_catching(|| -> i32 { panic!("boom {}", 1) }),
+        None
+    );
+    assert_eq!(err_log.entries().len(), 1);
+    let entry = &err_log.entries()[0];
+    assert_eq!(entry.get_level(), LevelFilter::Error);
+    assert!(entry
+        .get_message(err_log.get_format_mode())
+        .ends_with("panicked:\nboom 1"));
+}
+
+#[test]
+fn panic_hook() {
+    let log: SharedErrorLog<(), String> = Arc::new(Mutex::new(ErrorLog::new()));
+    install_panic_hook(log.clone(), false);
+    let res = thread::Builder::new()
+        .name("worker".into())
+        .spawn(|| panic!("worker failed"))
+        .unwrap()
+        .join();
+    let _ = std::panic::take_hook();
+    assert!(res.is_err());
+    // other tests of this binary may panic while the hook is installed
+    let log = log.lock().unwrap();
+    let msgs: Vec<String> = log
+        .entries()
+        .iter()
+        .map(|entry| entry.get_message(log.get_format_mode()))
+        .filter(|msg| msg.contains("'worker'"))
+        .collect();
+    assert_eq!(msgs.len(), 1);
+    let msg = &msgs[0];
+    assert!(
+        msg.starts_with("thread 'worker' panicked at tests"),
+        "{msg}"
+    );
+    assert!(msg.ends_with(":\nworker failed"), "{msg}");
+}
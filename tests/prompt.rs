@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+#![cfg(feature = "errors")]
+use error_log::{Choice, ErrorLog, Prompt, TimestampFormat};
+use pretty_assertions::assert_eq;
+use std::{
+    io::{Cursor, Write},
+    sync::{Arc, Mutex},
+};
+
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn err_log(input: &str, output: Output) -> ErrorLog<i32, String> {
+    let mut err_log = ErrorLog::new();
+    err_log
+        .timestamp_format(TimestampFormat::None)
+        .set_prompt(Prompt::with_io(Cursor::new(input.to_owned()), output));
+    err_log
+}
+
+#[test]
+fn prompt() {
+    let output = Output::default();
+    let mut err_log = err_log("x\nretry\ni\n", output.clone());
+    assert_eq!(err_log.push_err_prompt("failed"), Choice::Retry);
+    assert_eq!(
+        err_log.push_result_prompt(Err::<i32, _>("failed again")),
+        Err(Choice::Ignore)
+    );
+    assert_eq!(err_log.push_result_prompt(Ok::<_, String>(1)), Ok(1));
+    assert_eq!(err_log.entries().len(), 2);
+    assert_eq!(err_log.undisplayed_count(), 0);
+    assert_eq!(
+        String::from_utf8(output.0.lock().unwrap().clone()).unwrap(),
+        "ERROR failed\n[r]etry, [i]gnore, [a]bort? [r]etry, [i]gnore, [a]bort? \
+         ERROR failed again\n[r]etry, [i]gnore, [a]bort? "
+    );
+}
+
+#[test]
+fn choices_and_fallback() {
+    let mut prompt = Prompt::with_io(Cursor::new("r\n"), Output::default());
+    prompt
+        .choices([Choice::Ignore, Choice::Abort])
+        .fallback(Choice::Ignore);
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.set_prompt(prompt);
+    // "r" is not offered, then the input ends
+    assert_eq!(err_log.push_err_prompt("failed"), Choice::Ignore);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "ratatui")]
+use error_log::{log_warn, DropPolicy, ErrorLog, ErrorLogWidget, ErrorLogWidgetState, LevelFilter};
+use pretty_assertions::assert_eq;
+use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+
+fn render(
+    err_log: &ErrorLog<i32, String>,
+    filter: LevelFilter,
+    state: &mut ErrorLogWidgetState,
+) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(30, 18)).unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_stateful_widget(
+                ErrorLogWidget::new(err_log).filter(filter),
+                frame.area(),
+                state,
+            )
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+}
+
+#[test]
+fn widget() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.push_err("failed");
+    log_warn!(err_log, "careful\nsecond line");
+    let mut state = ErrorLogWidgetState::default();
+    state.select_next();
+    state.select_next();
+    let buf = render(&err_log, LevelFilter::Trace, &mut state);
+    assert_eq!(line(&buf, 0).trim_end(), "  ERROR failed");
+    assert_eq!(line(&buf, 1).trim_end(), "> WARN  careful");
+    assert_eq!(buf[(2, 0)].fg, Color::Red);
+    assert_eq!(state.selected(), Some(1));
+    // Detail pane
+    let detail: Vec<String> = (0..18)
+        .map(|y| line(&buf, y))
+        .skip_while(|line| !line.starts_with("┌Details"))
+        .collect();
+    assert_eq!(detail[1], "│WARN                        │");
+    assert_eq!(detail[4], "│careful                     │");
+    assert_eq!(detail[5], "│second line                 │");
+
+    // Warnings get filtered, selection is clamped
+    let buf = render(&err_log, LevelFilter::Error, &mut state);
+    assert_eq!(line(&buf, 1).trim_end(), "");
+    assert_eq!(state.selected(), Some(0));
+}
+
+#[test]
+fn rendered_entries_are_displayed() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.drop_policy(DropPolicy::DebugAssertPanic);
+    err_log.push_err("failed");
+    log_warn!(err_log, "careful");
+    render(
+        &err_log,
+        LevelFilter::Trace,
+        &mut ErrorLogWidgetState::default(),
+    );
+    assert_eq!(err_log.undisplayed_count(), 0);
+}
@@ -0,0 +1,85 @@
+#![cfg(all(feature = "std", feature = "errors", feature = "messages"))]
+use error_log::{Backoff, EntryContent, ErrorLog, LevelFilter, RetryPolicy};
+use pretty_assertions::assert_eq;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+mod common;
+use common::leveled_messages;
+
+fn recording(mut policy: RetryPolicy) -> (RetryPolicy, Arc<Mutex<Vec<Duration>>>) {
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    policy.sleeper(move |delay| recorded.lock().unwrap().push(delay));
+    (policy, delays)
+}
+
+fn flaky(failures: usize) -> impl FnMut() -> Result<i32, String> {
+    let mut calls = 0;
+    move || {
+        calls += 1;
+        match calls > failures {
+            true => Ok(calls as i32),
+            false => Err(format!("call {calls} failed")),
+        }
+    }
+}
+
+#[test]
+fn succeeds_after_retries() {
+    let (policy, delays) = recording(RetryPolicy::fixed(3, Duration::from_millis(10)));
+    let mut err_log = ErrorLog::<i32, String>::new();
+    assert!(err_log.retry(&policy, flaky(2)));
+    assert_eq!(*err_log.ok(), Some(3));
+    assert_eq!(
+        leveled_messages(&err_log),
+        vec![
+            (LevelFilter::Warn, "attempt 1/3 failed".into()),
+            (LevelFilter::Warn, "call 1 failed".into()),
+            (LevelFilter::Warn, "attempt 2/3 failed".into()),
+            (LevelFilter::Warn, "call 2 failed".into()),
+        ]
+    );
+    assert_eq!(*delays.lock().unwrap(), vec![Duration::from_millis(10); 2]);
+}
+
+#[test]
+fn final_failure_is_error() {
+    let (policy, delays) = recording(RetryPolicy::exponential(
+        4,
+        Duration::from_millis(100),
+        Duration::from_millis(300),
+    ));
+    let mut err_log = ErrorLog::<i32, String>::new();
+    assert!(!err_log.retry(&policy, flaky(10)));
+    assert_eq!(*err_log.ok(), None);
+    let errors = err_log
+        .entries()
+        .iter()
+        .filter(|entry| matches!(entry.content, EntryContent::Error(_)))
+        .count();
+    assert_eq!(errors, 4);
+    let entries = leveled_messages(&err_log);
+    assert_eq!(entries.len(), 7);
+    assert_eq!(entries[4], (LevelFilter::Warn, "attempt 3/4 failed".into()));
+    assert_eq!(entries[6], (LevelFilter::Error, "call 4 failed".into()));
+    assert_eq!(
+        *delays.lock().unwrap(),
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(300)
+        ]
+    );
+}
+
+#[test]
+fn delays() {
+    let policy = RetryPolicy::new(2, Backoff::None);
+    assert_eq!(policy.delay(1), Duration::ZERO);
+    let policy = RetryPolicy::exponential(100, Duration::from_secs(1), Duration::from_secs(60));
+    assert_eq!(policy.delay(3), Duration::from_secs(4));
+    assert_eq!(policy.delay(80), Duration::from_secs(60));
+}
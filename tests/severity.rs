@@ -0,0 +1,87 @@
+#![cfg(all(feature = "errors", feature = "messages"))]
+use error_log::{ErrorLog, LevelFilter, Severity};
+use pretty_assertions::assert_eq;
+use std::fmt;
+
+#[derive(Debug)]
+enum PluginError {
+    Missing(&'static str),
+    Crashed,
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Missing(name) => write!(f, "optional plugin {name} failed to load"),
+            PluginError::Crashed => write!(f, "plugin crashed"),
+        }
+    }
+}
+
+impl Severity for PluginError {
+    fn severity(&self) -> LevelFilter {
+        match self {
+            PluginError::Missing(_) => LevelFilter::Warn,
+            PluginError::Crashed => LevelFilter::Error,
+        }
+    }
+}
+
+fn shown(err_log: &ErrorLog<(), PluginError>) -> Vec<(LevelFilter, String)> {
+    err_log
+        .entries()
+        .iter()
+        .filter_map(|entry| {
+            let msg =
+                entry.get_message_filter(err_log.get_format_mode(), err_log.get_max_level())?;
+            Some((entry.get_level(), msg))
+        })
+        .collect()
+}
+
+#[test]
+fn with_level() {
+    let mut err_log = ErrorLog::<(), String>::new();
+    err_log.push_err_with_level("cache unavailable", LevelFilter::Info);
+    assert_eq!(
+        err_log.push_result_with_level(
+            "1".parse::<i32>().map_err(|e| e.to_string()),
+            LevelFilter::Warn
+        ),
+        Some(1)
+    );
+    err_log.push_result_with_level::<i32, _>(Err("no config"), LevelFilter::Warn);
+    err_log.push_err("fatal");
+    err_log.max_level(LevelFilter::Warn);
+    let levels: Vec<LevelFilter> = err_log.entries().iter().map(|e| e.get_level()).collect();
+    assert_eq!(
+        levels,
+        vec![LevelFilter::Info, LevelFilter::Warn, LevelFilter::Error]
+    );
+    assert_eq!(err_log.entries_cloned().len(), 2);
+}
+
+#[test]
+fn severity_filters() {
+    let mut err_log = ErrorLog::<(), PluginError>::new();
+    err_log.use_severity().max_level(LevelFilter::Error);
+    err_log.push_err(PluginError::Missing("spell-check"));
+    err_log.push_err(PluginError::Crashed);
+    assert_eq!(
+        shown(&err_log),
+        vec![(LevelFilter::Error, "plugin crashed".to_string())]
+    );
+    err_log.max_level(LevelFilter::Off);
+    assert_eq!(shown(&err_log).len(), 1);
+    err_log.max_level(LevelFilter::Warn);
+    assert_eq!(
+        shown(&err_log),
+        vec![
+            (
+                LevelFilter::Warn,
+                "optional plugin spell-check failed to load".to_string()
+            ),
+            (LevelFilter::Error, "plugin crashed".to_string())
+        ]
+    );
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "errors")]
+use error_log::{ErrorLog, Span};
+use pretty_assertions::assert_eq;
+
+const SOURCE: &str = "name = \"demo\"\nport = \"abc\"\n";
+
+#[test]
+fn render() {
+    let span = Span::new("config.toml", SOURCE, 21..26, "expected a number");
+    assert_eq!(span.line_column(), (2, 8));
+    assert_eq!(
+        span.render(),
+        " --> config.toml:2:8\n  |\n2 | port = \"abc\"\n  |        ^^^^^ expected a number"
+    );
+    // Out of range spans get clamped
+    let span = Span::new("config.toml", SOURCE, 100..200, "");
+    assert_eq!(span.line_column(), (3, 1));
+    assert_eq!(span.render(), " --> config.toml:3:1\n  |\n3 | \n  | ^");
+}
+
+#[test]
+fn diagnostics() {
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.push_err_span(
+        "invalid port",
+        Span::new("config.toml", SOURCE, 21..26, "expected a number"),
+    );
+    err_log.push_err("missing host");
+    assert_eq!(
+        err_log.render_diagnostics(),
+        "error: invalid port\n --> config.toml:2:8\n  |\n2 | port = \"abc\"\n  |        ^^^^^ expected a number\n\
+         \nerror: missing host\n"
+    );
+    assert!(err_log.join_to_string().unwrap().contains("^^^^^"));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+use error_log::{ErrorLog, TimestampFormat};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn well_known() {
+    assert_eq!(
+        TimestampFormat::Rfc3339.format(0, 0),
+        "1970-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        TimestampFormat::Rfc2822.format(0, 0),
+        "Thu, 01 Jan 1970 00:00:00 +0000"
+    );
+    assert_eq!(TimestampFormat::None.format(0, 0), "");
+}
+
+#[test]
+fn custom() {
+    let format = TimestampFormat::custom("[year]-[month]");
+    assert_eq!(format.format(0, 0), "1970-01");
+    assert_eq!(format, TimestampFormat::custom("[year]-[month]"));
+    // Invalid format descriptions fall back to the unix timestamp
+    let format = TimestampFormat::custom("[invalid");
+    assert_eq!(format.format(42, 0), "42");
+}
+
+#[test]
+fn elapsed() {
+    assert_eq!(TimestampFormat::Elapsed.format(3725, 0), "+01:02:05");
+    let mut err_log = ErrorLog::<i32, String>::new();
+    err_log.timestamp_format(TimestampFormat::Elapsed);
+    assert_eq!(err_log.format_timestamp(0).chars().next(), Some('-'));
+}
+
+#[test]
+fn out_of_range() {
+    for format in [
+        TimestampFormat::Rfc3339,
+        TimestampFormat::Rfc2822,
+        TimestampFormat::Local,
+    ] {
+        assert_eq!(format.format(i64::MAX, 0), i64::MAX.to_string());
+    }
+    assert!(!TimestampFormat::Elapsed
+        .format(i64::MIN, i64::MAX)
+        .is_empty());
+}